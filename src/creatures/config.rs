@@ -0,0 +1,605 @@
+use crate::{
+    creatures::{
+        external::{ExternalController, ExternalEntity},
+        learner::LearnerEntity,
+        neural::NeuralEntity,
+        tree::TreeEntity,
+    },
+    entity::{
+        Entity,
+        ageing::Ageing,
+        decision::DecisionTree,
+        genome::{GENE_COUNT, GENE_NAMES, Genome},
+        instinct::Behaviour,
+        learning::QTable,
+        lifestate::LifeState,
+        network::random_weights,
+        species::{Diet, Species, SpeciesInfo, SpeciesRegistry},
+    },
+};
+use std::{cell::RefCell, fmt, fs, path::Path, rc::Rc, sync::OnceLock};
+
+/// Derlemeye gömülü tür tanımları
+/// Hazır türlerin (`SpeciesRegistry::default`) tek kaynağıdır.
+pub const DEFAULT_SPECIES_CONFIG: &str = include_str!("../../config/species.cfg");
+
+/// Yapılandırma dosyasında ki hata
+/// `line` hatanın bulunduğu satırdır (1'den başlar, dosya okunamazsa 0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "satır {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Türün kararlarını neyin verdiği
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BrainKind {
+    /// Beslenme biçimine göre hazır karar ağacı
+    #[default]
+    Tree,
+    /// Tür içinde paylaşılan tablo ile öğrenen canlı
+    QLearning,
+    /// Genomdaki sinir ağı ile karar veren, nesiller boyunca evrilen canlı
+    Neural,
+    /// Kararları dış bir kontrolcüye soran canlı
+    /// Dosyadan seçilemez, `CreatureFactory::with_controller` ile kurulur.
+    External,
+}
+
+/// Dosyadan okunan tek bir tür tanımı
+#[derive(Debug, Clone)]
+pub struct SpeciesConfig {
+    /// Kayıt listesine eklenecek bilgiler
+    pub info: SpeciesInfo,
+    /// İçgüdü eşikleri
+    pub behaviour: Behaviour,
+    /// Karar mekanizması
+    pub brain: BrainKind,
+}
+
+impl SpeciesConfig {
+    /// Türü kayıt listesine ekle ve bu türden canlı üreten fabrikayı döndür
+    /// Fabrika kayıt listesine de eklenir, `World::spawn` isimle kullanabilir.
+    pub fn register(&self, registry: &mut SpeciesRegistry) -> CreatureFactory {
+        let species = registry.register(self.info.clone());
+        let factory = CreatureFactory {
+            species,
+            diet: self.info.diet,
+            genome: self.info.genome.clone(),
+            behaviour: self.behaviour,
+            brain: self.brain,
+            tree: Rc::new(DecisionTree::for_diet(self.info.diet)),
+            table: (self.brain == BrainKind::QLearning)
+                .then(|| Rc::new(RefCell::new(QTable::default()))),
+            controller: None,
+        };
+        factory.install(registry);
+        factory
+    }
+}
+
+/// Yapılandırılmış bir türden canlı üretir
+/// Karar mekanizması türün `brain` değerine göre seçilir.
+#[derive(Debug, Clone)]
+pub struct CreatureFactory {
+    pub species: Species,
+    pub diet: Diet,
+    pub genome: Genome,
+    pub behaviour: Behaviour,
+    /// Karar mekanizması
+    pub brain: BrainKind,
+    /// Üretilen canlıların paylaştığı karar ağacı
+    pub tree: Rc<DecisionTree>,
+    /// Varsa canlılar öğrenir ve bu tabloyu paylaşır (kaydetmek için de kullanılır)
+    pub table: Option<Rc<RefCell<QTable>>>,
+    /// Varsa canlılar kararlarını bu kontrolcüye sorar
+    pub controller: Option<Rc<RefCell<ExternalController>>>,
+}
+
+impl CreatureFactory {
+    /// Üretilen canlılar kararlarını dış kontrolcüye sorar
+    /// Kayıt listesindeki fabrikanın da değişmesi için `install` çağrılmalı.
+    pub fn with_controller(mut self, controller: Rc<RefCell<ExternalController>>) -> Self {
+        self.brain = BrainKind::External;
+        self.controller = Some(controller);
+        self
+    }
+
+    /// Bu fabrikayı türün kayıtlı fabrikası yap, `World::spawn` isimle kullanabilir
+    pub fn install(&self, registry: &mut SpeciesRegistry) {
+        let spawner = self.clone();
+        registry.set_factory(self.species, Rc::new(move || spawner.spawn()));
+    }
+
+    /// Yeni, yetişkin ve tam dolu bir canlı üret
+    /// Sinir ağlı canlılar her seferinde rastgele ağırlıklarla başlar.
+    pub fn spawn(&self) -> Box<dyn Entity> {
+        let mut genome = self.genome.clone();
+        match (self.brain, &self.table, &self.controller) {
+            (BrainKind::QLearning, Some(table), _) => {
+                return Box::new(LearnerEntity::new(
+                    LifeState::new(genome),
+                    self.species,
+                    self.diet,
+                    self.behaviour,
+                    table.clone(),
+                ));
+            }
+            (BrainKind::Neural, _, _) => {
+                genome.weights = random_weights();
+                return Box::new(NeuralEntity::new(
+                    LifeState::new(genome),
+                    self.species,
+                    self.diet,
+                    self.behaviour,
+                ));
+            }
+            (BrainKind::External, _, Some(controller)) => {
+                return Box::new(ExternalEntity::new(
+                    LifeState::new(genome),
+                    self.species,
+                    controller.clone(),
+                ));
+            }
+            _ => {}
+        }
+        Box::new(
+            TreeEntity::new(
+                LifeState::new(genome),
+                self.species,
+                self.diet,
+                self.behaviour,
+            )
+            .with_tree(self.tree.clone()),
+        )
+    }
+}
+
+/// Okunmakta olan, henüz doğrulanmamış tür bölümü
+struct SectionBuilder {
+    name: String,
+    line: usize,
+    glyph: char,
+    color: (u8, u8, u8),
+    diet: Option<Diet>,
+    genes: [Option<usize>; GENE_COUNT],
+    behaviour: Behaviour,
+    brain: BrainKind,
+    ageing: Ageing,
+}
+
+impl SectionBuilder {
+    fn new(name: String, line: usize) -> Self {
+        Self {
+            name,
+            line,
+            glyph: '@',
+            color: (200, 200, 200),
+            diet: None,
+            genes: [None; GENE_COUNT],
+            behaviour: Behaviour::default(),
+            brain: BrainKind::Tree,
+            ageing: Ageing::default(),
+        }
+    }
+
+    /// `anahtar = değer` satırını uygula
+    fn set(&mut self, line: usize, key: &str, value: &str) -> Result<(), ConfigError> {
+        match key {
+            "glyph" => {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.glyph = c,
+                    _ => return Err(ConfigError::new(line, "glyph tek karakter olmalı")),
+                }
+            }
+            "color" => self.color = parse_color(line, value)?,
+            "diet" => {
+                self.diet = Some(match value {
+                    "herbivore" => Diet::Herbivore,
+                    "carnivore" => Diet::Carnivore,
+                    "omnivore" => Diet::Omnivore,
+                    _ => {
+                        return Err(ConfigError::new(
+                            line,
+                            format!(
+                                "bilinmeyen diet '{}' (herbivore, carnivore, omnivore)",
+                                value
+                            ),
+                        ));
+                    }
+                })
+            }
+            "brain" => {
+                self.brain = match value {
+                    "tree" => BrainKind::Tree,
+                    "qlearning" => BrainKind::QLearning,
+                    "neural" => BrainKind::Neural,
+                    _ => {
+                        return Err(ConfigError::new(
+                            line,
+                            format!("bilinmeyen brain '{}' (tree, qlearning, neural)", value),
+                        ));
+                    }
+                }
+            }
+            "threat_range" => self.behaviour.threat_range = parse_number(line, key, value)?,
+            "courage" => self.behaviour.courage = parse_number(line, key, value)?,
+            "hunger" => self.behaviour.hunger = parse_percent(line, key, value)?,
+            "thirst" => self.behaviour.thirst = parse_percent(line, key, value)?,
+            "fatigue" => self.behaviour.fatigue = parse_percent(line, key, value)?,
+            "curiosity" => self.behaviour.curiosity = parse_percent(line, key, value)?,
+            "cohesion" => self.behaviour.cohesion = parse_number(line, key, value)?,
+            "separation" => self.behaviour.separation = parse_number(line, key, value)?,
+            "alignment" => self.behaviour.alignment = parse_number(line, key, value)?,
+            "herd_safety" => self.behaviour.herd_safety = parse_number(line, key, value)?,
+            "juvenile_vigor" => self.ageing.juvenile = parse_percent(line, key, value)?,
+            "ageing_onset" => self.ageing.onset = parse_percent(line, key, value)?,
+            "ageing_decline" => self.ageing.decline = parse_percent(line, key, value)?,
+            _ => {
+                let Some(index) = GENE_NAMES.iter().position(|gene| *gene == key) else {
+                    return Err(ConfigError::new(
+                        line,
+                        format!("bilinmeyen anahtar '{}'", key),
+                    ));
+                };
+                self.genes[index] = Some(parse_number(line, key, value)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Bölümü doğrula ve tür tanımına çevir
+    fn finish(self) -> Result<SpeciesConfig, ConfigError> {
+        let diet = self.diet.ok_or_else(|| {
+            ConfigError::new(self.line, format!("[{}] için diet eksik", self.name))
+        })?;
+
+        let mut genes = [0; GENE_COUNT];
+        for (index, value) in self.genes.iter().enumerate() {
+            genes[index] = value.ok_or_else(|| {
+                ConfigError::new(
+                    self.line,
+                    format!("[{}] için {} eksik", self.name, GENE_NAMES[index]),
+                )
+            })?;
+        }
+        let mut genome = Genome::from_genes(genes);
+        genome.ageing = self.ageing;
+
+        if genome.max_age == 0
+            || genome.max_health == 0
+            || genome.max_energy == 0
+            || genome.max_water == 0
+        {
+            return Err(ConfigError::new(
+                self.line,
+                format!(
+                    "[{}] max_age, max_health, max_energy ve max_water 0 olamaz",
+                    self.name
+                ),
+            ));
+        }
+        if genome.speed == 0
+            || genome.vision_range == 0
+            || genome.max_fatigue == 0
+            || genome.gestation_period == 0
+        {
+            return Err(ConfigError::new(
+                self.line,
+                format!(
+                    "[{}] speed, vision_range, max_fatigue ve gestation_period 0 olamaz",
+                    self.name
+                ),
+            ));
+        }
+        if genome.maturity_age >= genome.max_age {
+            return Err(ConfigError::new(
+                self.line,
+                format!("[{}] maturity_age, max_age'den küçük olmalı", self.name),
+            ));
+        }
+        if self.ageing.juvenile == 0 || self.ageing.decline == 0 {
+            return Err(ConfigError::new(
+                self.line,
+                format!(
+                    "[{}] juvenile_vigor ve ageing_decline en az 1 olmalı",
+                    self.name
+                ),
+            ));
+        }
+        if genome.litter_size == 0 {
+            return Err(ConfigError::new(
+                self.line,
+                format!("[{}] litter_size en az 1 olmalı", self.name),
+            ));
+        }
+
+        Ok(SpeciesConfig {
+            info: SpeciesInfo {
+                name: self.name,
+                glyph: self.glyph,
+                color: self.color,
+                diet,
+                genome,
+            },
+            behaviour: self.behaviour,
+            brain: self.brain,
+        })
+    }
+}
+
+fn parse_number(line: usize, key: &str, value: &str) -> Result<usize, ConfigError> {
+    value.parse::<usize>().map_err(|_| {
+        ConfigError::new(
+            line,
+            format!("{} pozitif bir tam sayı olmalı, '{}' verildi", key, value),
+        )
+    })
+}
+
+fn parse_percent(line: usize, key: &str, value: &str) -> Result<usize, ConfigError> {
+    let percent = parse_number(line, key, value)?;
+    if percent > 100 {
+        return Err(ConfigError::new(
+            line,
+            format!("{} 0 ile 100 arasında olmalı", key),
+        ));
+    }
+    Ok(percent)
+}
+
+fn parse_color(line: usize, value: &str) -> Result<(u8, u8, u8), ConfigError> {
+    let parts: Vec<&str> = value.split(',').map(str::trim).collect();
+    let [r, g, b] = parts.as_slice() else {
+        return Err(ConfigError::new(line, "color 'r, g, b' biçiminde olmalı"));
+    };
+    let channel = |c: &str| {
+        c.parse::<u8>()
+            .map_err(|_| ConfigError::new(line, format!("renk değeri 0-255 olmalı: '{}'", c)))
+    };
+    Ok((channel(r)?, channel(g)?, channel(b)?))
+}
+
+/// Tür tanımlarını metinden oku
+///
+/// Biçim:
+/// ```text
+/// # yorum
+/// [tür_adı]
+/// diet = herbivore
+/// max_age = 105
+/// ...
+/// ```
+/// Her bölüm bir türdür. Tüm genler ve `diet` zorunludur;
+/// `glyph`, `color` ve davranış ağırlıkları (`threat_range`, `courage`,
+/// `hunger`, `thirst`, `fatigue`, `curiosity`) ve sürü ağırlıkları (`cohesion`,
+/// `separation`, `alignment`, `herd_safety`) verilmezse varsayılanları kullanılır.
+/// `brain = qlearning` verilirse tür hazır ağaç yerine öğrenerek karar verir,
+/// `brain = neural` verilirse kararları kalıtılan bir sinir ağı verir.
+pub fn parse_species_config(text: &str) -> Result<Vec<SpeciesConfig>, ConfigError> {
+    let mut configs: Vec<SpeciesConfig> = Vec::new();
+    let mut current: Option<SectionBuilder> = None;
+
+    for (index, raw) in text.lines().enumerate() {
+        let line = index + 1;
+        let content = raw.split('#').next().unwrap_or("").trim();
+        if content.is_empty() {
+            continue;
+        }
+
+        // Yeni tür bölümü
+        if let Some(header) = content.strip_prefix('[') {
+            let Some(name) = header.strip_suffix(']').map(str::trim) else {
+                return Err(ConfigError::new(line, "bölüm başlığı ']' ile bitmeli"));
+            };
+            if name.is_empty() {
+                return Err(ConfigError::new(line, "tür adı boş olamaz"));
+            }
+            let duplicate = configs.iter().any(|c| c.info.name == name)
+                || current.as_ref().is_some_and(|c| c.name == name);
+            if duplicate {
+                return Err(ConfigError::new(
+                    line,
+                    format!("[{}] birden fazla tanımlanmış", name),
+                ));
+            }
+            if let Some(section) = current.take() {
+                configs.push(section.finish()?);
+            }
+            current = Some(SectionBuilder::new(name.to_string(), line));
+            continue;
+        }
+
+        let Some((key, value)) = content.split_once('=') else {
+            return Err(ConfigError::new(line, "'anahtar = değer' bekleniyordu"));
+        };
+        let Some(section) = current.as_mut() else {
+            return Err(ConfigError::new(
+                line,
+                "değer bir [tür] bölümünden önce geldi",
+            ));
+        };
+        section.set(line, key.trim(), value.trim())?;
+    }
+
+    if let Some(section) = current.take() {
+        configs.push(section.finish()?);
+    }
+    Ok(configs)
+}
+
+/// Tür tanımlarını dosyadan oku
+pub fn load_species_config(path: impl AsRef<Path>) -> Result<Vec<SpeciesConfig>, ConfigError> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)
+        .map_err(|err| ConfigError::new(0, format!("{} okunamadı: {}", path.display(), err)))?;
+    parse_species_config(&text)
+}
+
+/// Gömülü tür tanımları, ilk kullanımda bir kez okunur
+pub fn default_species_configs() -> &'static [SpeciesConfig] {
+    static CONFIGS: OnceLock<Vec<SpeciesConfig>> = OnceLock::new();
+    CONFIGS.get_or_init(|| {
+        parse_species_config(DEFAULT_SPECIES_CONFIG)
+            .unwrap_or_else(|err| panic!("gömülü species.cfg hatalı: {}", err))
+    })
+}
+
+/// Gömülü tanımlardaki hazır tür
+/// Sadece `config/species.cfg` içinde olan isimler ile çağrılmalı.
+pub fn default_species(name: &str) -> &'static SpeciesConfig {
+    default_species_configs()
+        .iter()
+        .find(|config| config.info.name == name)
+        .unwrap_or_else(|| panic!("gömülü species.cfg içinde [{}] yok", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "\
+# yorum
+[deer]
+glyph = d
+color = 10, 20, 30
+diet = herbivore
+max_age = 100
+max_health = 50
+max_energy = 40
+max_water = 30
+maturity_age = 10
+vision_range = 5
+speed = 2
+attack = 1
+defense = 3
+agility = 4
+max_fatigue = 20
+gestation_period = 8
+litter_size = 1
+reproduction_cooldown = 100
+courage = 80   # satır sonu yorumu
+";
+
+    /// Geçerli metinde `key` satırını `value` ile değiştir
+    fn with_value(key: &str, value: &str) -> String {
+        VALID
+            .lines()
+            .map(|line| {
+                if line.starts_with(key) {
+                    format!("{} = {}", key, value)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn parses_valid_section() {
+        let configs = parse_species_config(VALID).unwrap();
+        assert_eq!(configs.len(), 1);
+        let config = &configs[0];
+        assert_eq!(config.info.name, "deer");
+        assert_eq!(config.info.glyph, 'd');
+        assert_eq!(config.info.color, (10, 20, 30));
+        assert_eq!(config.info.diet, Diet::Herbivore);
+        assert_eq!(config.info.genome.speed, 2);
+        assert_eq!(config.behaviour.courage, 80);
+        assert_eq!(config.brain, BrainKind::Tree);
+    }
+
+    #[test]
+    fn embedded_config_is_valid() {
+        let names: Vec<&str> = default_species_configs()
+            .iter()
+            .map(|config| config.info.name.as_str())
+            .collect();
+        assert_eq!(names[..3], ["herbivore", "carnivore", "omnivore"]);
+    }
+
+    #[test]
+    fn default_registry_matches_config() {
+        let registry = SpeciesRegistry::default();
+        for (species, name) in [
+            (Species::HERBIVORE, "herbivore"),
+            (Species::CARNIVORE, "carnivore"),
+            (Species::OMNIVORE, "omnivore"),
+        ] {
+            let info = registry.get(species).unwrap();
+            assert_eq!(info.name, name);
+            assert_eq!(info.genome, default_species(name).info.genome);
+        }
+        assert_eq!(
+            TreeEntity::preset("carnivore").life_state.genome.ageing,
+            default_species("carnivore").info.genome.ageing
+        );
+    }
+
+    #[test]
+    fn reports_error_line() {
+        let err = parse_species_config(&with_value("speed", "hızlı")).unwrap_err();
+        assert_eq!(err.line, 12);
+
+        let err = parse_species_config(&with_value("diet", "plankton")).unwrap_err();
+        assert_eq!(err.line, 5);
+
+        let err = parse_species_config("max_age = 10\n").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = parse_species_config(&format!("{}wings = 2\n", VALID)).unwrap_err();
+        assert_eq!(err.line, 21);
+    }
+
+    #[test]
+    fn rejects_duplicate_section() {
+        let err = parse_species_config(&format!("{}{}", VALID, VALID)).unwrap_err();
+        assert_eq!(err.line, 22);
+    }
+
+    #[test]
+    fn rejects_missing_gene() {
+        let text: String = VALID
+            .lines()
+            .filter(|line| !line.starts_with("agility"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        let err = parse_species_config(&text).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert!(err.message.contains("agility"));
+    }
+
+    #[test]
+    fn rejects_zero_values() {
+        for key in [
+            "max_health",
+            "speed",
+            "vision_range",
+            "max_fatigue",
+            "gestation_period",
+            "litter_size",
+        ] {
+            let err = parse_species_config(&with_value(key, "0")).unwrap_err();
+            assert_eq!(err.line, 2, "{}", key);
+        }
+    }
+}
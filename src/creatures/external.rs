@@ -0,0 +1,233 @@
+use crate::entity::{
+    Entity,
+    genome::Genome,
+    intent::Intent,
+    lifestate::LifeState,
+    outcome::Outcome,
+    perception::Perception,
+    protocol::{PROTOCOL_VERSION, encode_outcome, encode_request, parse_reply},
+    species::Species,
+};
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{Child, Command, Stdio},
+    rc::Rc,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Kontrolcünün bir karara cevap vermesi için varsayılan süre
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// El sıkışma (`ready`) için beklenen en uzun süre
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Dışarıdaki bir programla satır tabanlı protokol üzerinden konuşan kontrolcü
+///
+/// Bir kontrolcü birden fazla canlıyı (ajan) yönetebilir.
+/// Cevap zamanında gelmezse canlı bekler (`Idle`), geç gelen cevaplar atılır.
+pub struct ExternalController {
+    /// Alt süreç olarak başlatıldıysa süreç
+    child: Option<Child>,
+    writer: Box<dyn Write>,
+    lines: Receiver<String>,
+    /// Karar başına bekleme süresi
+    pub timeout: Duration,
+    next_seq: usize,
+    next_agent: usize,
+    /// Zaman aşımına uğrayan karar sayısı
+    pub timeouts: usize,
+    /// Çözülemeyen cevap sayısı
+    pub invalid_replies: usize,
+}
+
+impl ExternalController {
+    /// Programı başlat ve stdin/stdout üzerinden el sıkış
+    pub fn spawn(program: &str, args: &[&str], timeout: Duration) -> io::Result<Self> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().ok_or(io::ErrorKind::BrokenPipe)?;
+        let stdout = child.stdout.take().ok_or(io::ErrorKind::BrokenPipe)?;
+        let mut controller = Self::from_streams(stdout, stdin, timeout)?;
+        controller.child = Some(child);
+        Ok(controller)
+    }
+
+    /// Hazır okuma/yazma akışları ile el sıkış (örn. yerel bir soket)
+    pub fn from_streams(
+        reader: impl Read + Send + 'static,
+        writer: impl Write + 'static,
+        timeout: Duration,
+    ) -> io::Result<Self> {
+        // Okuma ayrı bir iş parçacığında yapılır, böylece zaman aşımı uygulanabilir
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut controller = Self {
+            child: None,
+            writer: Box::new(writer),
+            lines,
+            timeout,
+            next_seq: 1,
+            next_agent: 1,
+            timeouts: 0,
+            invalid_replies: 0,
+        };
+        controller.send(&format!("curious {}\n", PROTOCOL_VERSION))?;
+        match controller.lines.recv_timeout(HANDSHAKE_TIMEOUT) {
+            Ok(line) if line.trim() == "ready" => Ok(controller),
+            Ok(line) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("'ready' bekleniyordu, '{}' geldi", line.trim()),
+            )),
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "kontrolcü el sıkışmaya cevap vermedi",
+            )),
+        }
+    }
+
+    /// Yeni bir ajan numarası ver
+    pub fn new_agent(&mut self) -> usize {
+        let agent = self.next_agent;
+        self.next_agent += 1;
+        agent
+    }
+
+    fn send(&mut self, text: &str) -> io::Result<()> {
+        self.writer.write_all(text.as_bytes())?;
+        self.writer.flush()
+    }
+
+    /// Ajan için niyet iste, cevap gelmezse `Idle`
+    pub fn request(&mut self, agent: usize, life: &LifeState, view: &Perception) -> Intent {
+        let idle = Intent::Idle { duration: 1 };
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if self.send(&encode_request(seq, agent, life, view)).is_err() {
+            return idle;
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            match self.lines.recv_timeout(left) {
+                Ok(line) => match parse_reply(&line) {
+                    Ok((reply_seq, intent)) if reply_seq == seq => return intent,
+                    // Önceki, zaman aşımına uğramış bir isteğin cevabı
+                    Ok((reply_seq, _)) if reply_seq < seq => continue,
+                    _ => {
+                        self.invalid_replies += 1;
+                        return idle;
+                    }
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    self.timeouts += 1;
+                    return idle;
+                }
+                Err(RecvTimeoutError::Disconnected) => return idle,
+            }
+        }
+    }
+
+    /// Tick sonucunu bildir, cevap beklenmez
+    pub fn notify(&mut self, agent: usize, outcome: &Outcome) {
+        let _ = self.send(&encode_outcome(agent, outcome));
+    }
+}
+
+impl fmt::Debug for ExternalController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalController")
+            .field("timeout", &self.timeout)
+            .field("timeouts", &self.timeouts)
+            .field("invalid_replies", &self.invalid_replies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ExternalController {
+    fn drop(&mut self) {
+        let _ = self.send("quit\n");
+        if let Some(child) = &mut self.child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Kararlarını dışarıdaki bir kontrolcüye soran canlı
+pub struct ExternalEntity {
+    pub life_state: LifeState,
+    pub species: Species,
+    /// Kontrolcüdeki ajan numarası
+    pub agent: usize,
+    pub controller: Rc<RefCell<ExternalController>>,
+}
+
+impl ExternalEntity {
+    pub fn new(
+        life_state: LifeState,
+        species: Species,
+        controller: Rc<RefCell<ExternalController>>,
+    ) -> Self {
+        let agent = controller.borrow_mut().new_agent();
+        Self {
+            life_state,
+            species,
+            agent,
+            controller,
+        }
+    }
+}
+
+impl Entity for ExternalEntity {
+    fn life(&self) -> &LifeState {
+        &self.life_state
+    }
+
+    fn life_mut(&mut self) -> &mut LifeState {
+        &mut self.life_state
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn make_intent(&self, perception: Perception) -> Intent {
+        self.controller
+            .borrow_mut()
+            .request(self.agent, &self.life_state, &perception)
+    }
+
+    fn outcome(&mut self, outcome: &Outcome) {
+        self.controller.borrow_mut().notify(self.agent, outcome);
+    }
+
+    fn tick(&mut self) {
+        self.life_state.tick();
+    }
+
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+        let genome = self.life_state.genome.inherit(partner);
+        Box::new(ExternalEntity::new(
+            LifeState::newborn(genome),
+            self.species,
+            self.controller.clone(),
+        ))
+    }
+}
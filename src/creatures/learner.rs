@@ -0,0 +1,146 @@
+use crate::entity::{
+    Entity,
+    decision::{DecisionContext, action_node},
+    genome::Genome,
+    instinct::Behaviour,
+    intent::Intent,
+    learning::{QTable, encode_state, reward},
+    lifestate::LifeState,
+    outcome::Outcome,
+    perception::Perception,
+    species::{Diet, Species},
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Kararlarını tabular Q-learning ile öğrenen canlı
+///
+/// Eylemler hazır karar düğümleridir (yemek ara, kaç, eş bul ...).
+/// Aynı türden tüm canlılar ve yavruları tek bir tabloyu paylaşır,
+/// böylece nesiller boyunca öğrenmeye devam edilir.
+pub struct LearnerEntity {
+    pub life_state: LifeState,
+    pub species: Species,
+    /// Hangi yemeklerin yenebileceği
+    pub diet: Diet,
+    /// İçgüdü eşikleri (tehdit algısı için)
+    pub behaviour: Behaviour,
+    /// Paylaşılan değer tablosu
+    pub table: Rc<RefCell<QTable>>,
+    /// Kapalıysa tablo güncellenmez ve her zaman en iyi eylem seçilir
+    pub learning: bool,
+    /// Son kararın durumu ve eylemi
+    pending: Option<(u32, usize)>,
+    /// Son karardan beri biriken ödül
+    reward: f32,
+}
+
+impl LearnerEntity {
+    pub fn new(
+        life_state: LifeState,
+        species: Species,
+        diet: Diet,
+        behaviour: Behaviour,
+        table: Rc<RefCell<QTable>>,
+    ) -> Self {
+        Self {
+            life_state,
+            species,
+            diet,
+            behaviour,
+            table,
+            learning: true,
+            pending: None,
+            reward: 0.0,
+        }
+    }
+
+    /// Öğrenmeyi kapat, sadece öğrenilmiş davranışı uygula
+    pub fn frozen(mut self) -> Self {
+        self.learning = false;
+        self
+    }
+}
+
+impl Entity for LearnerEntity {
+    fn life(&self) -> &LifeState {
+        &self.life_state
+    }
+
+    fn life_mut(&mut self) -> &mut LifeState {
+        &mut self.life_state
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn behaviour(&self) -> Behaviour {
+        self.behaviour
+    }
+
+    fn observe(&mut self, view: &Perception) {
+        let radius = self.life_state.vision_range();
+        self.life_state.memory.observe(view, self.species, radius);
+
+        let ctx = DecisionContext::new(&self.life_state, view, self.species, &self.behaviour);
+        let state = encode_state(&ctx, self.diet);
+
+        let mut table = self.table.borrow_mut();
+        if self.learning
+            && let Some((last_state, action)) = self.pending
+        {
+            table.update(last_state, action, self.reward, Some(state));
+        }
+        self.reward = 0.0;
+
+        let action = if self.learning {
+            table.choose(state)
+        } else {
+            table.best(state)
+        };
+        self.pending = Some((state, action));
+    }
+
+    fn make_intent(&self, perception: Perception) -> Intent {
+        let Some((_, action)) = self.pending else {
+            return Intent::Idle { duration: 1 };
+        };
+        let ctx =
+            DecisionContext::new(&self.life_state, &perception, self.species, &self.behaviour);
+        action_node(self.diet, action)
+            .run(&ctx)
+            .unwrap_or(Intent::Idle { duration: 1 })
+    }
+
+    fn outcome(&mut self, outcome: &Outcome) {
+        self.reward += reward(outcome);
+        // Ölümde gelecek yoktur, son eylem hemen cezalandırılır
+        if outcome.died {
+            if self.learning
+                && let Some((state, action)) = self.pending.take()
+            {
+                self.table
+                    .borrow_mut()
+                    .update(state, action, self.reward, None);
+            }
+            self.reward = 0.0;
+        }
+    }
+
+    fn tick(&mut self) {
+        self.life_state.tick();
+    }
+
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+        let genome = self.life_state.genome.inherit(partner);
+        let mut child = LearnerEntity::new(
+            LifeState::newborn(genome),
+            self.species,
+            self.diet,
+            self.behaviour,
+            self.table.clone(),
+        );
+        child.learning = self.learning;
+        Box::new(child)
+    }
+}
@@ -1,3 +1,5 @@
-pub mod herbivore;
-pub mod carnivore;
-pub mod omnivore;
+pub mod config;
+pub mod external;
+pub mod learner;
+pub mod neural;
+pub mod tree;
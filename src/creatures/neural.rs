@@ -0,0 +1,77 @@
+use crate::entity::{
+    Entity,
+    decision::{DecisionContext, action_node},
+    genome::Genome,
+    instinct::Behaviour,
+    intent::Intent,
+    lifestate::LifeState,
+    network::{decide, encode_inputs},
+    perception::Perception,
+    species::{Diet, Species},
+};
+
+/// Kararlarını genomundaki küçük bir sinir ağı ile veren canlı
+///
+/// Ağırlıklar genomun parçasıdır; yavru ebeveynlerinin ağırlıklarını
+/// çaprazlanmış ve mutasyona uğramış olarak alır. Böylece davranış
+/// nesiller boyunca evrilir. Ağın çıktısı hazır eylem düğümlerinden birini seçer.
+pub struct NeuralEntity {
+    pub life_state: LifeState,
+    pub species: Species,
+    /// Hangi yemeklerin yenebileceği
+    pub diet: Diet,
+    /// İçgüdü eşikleri (tehdit algısı için)
+    pub behaviour: Behaviour,
+}
+
+impl NeuralEntity {
+    pub fn new(life_state: LifeState, species: Species, diet: Diet, behaviour: Behaviour) -> Self {
+        Self {
+            life_state,
+            species,
+            diet,
+            behaviour,
+        }
+    }
+}
+
+impl Entity for NeuralEntity {
+    fn life(&self) -> &LifeState {
+        &self.life_state
+    }
+
+    fn life_mut(&mut self) -> &mut LifeState {
+        &mut self.life_state
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn behaviour(&self) -> Behaviour {
+        self.behaviour
+    }
+
+    fn make_intent(&self, perception: Perception) -> Intent {
+        let ctx =
+            DecisionContext::new(&self.life_state, &perception, self.species, &self.behaviour);
+        let inputs = encode_inputs(&ctx, self.diet);
+        decide(&self.life_state.genome.weights, &inputs)
+            .and_then(|action| action_node(self.diet, action).run(&ctx))
+            .unwrap_or(Intent::Idle { duration: 1 })
+    }
+
+    fn tick(&mut self) {
+        self.life_state.tick();
+    }
+
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+        let genome = self.life_state.genome.inherit(partner);
+        Box::new(NeuralEntity::new(
+            LifeState::newborn(genome),
+            self.species,
+            self.diet,
+            self.behaviour,
+        ))
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    creatures::config::default_species_configs,
+    entity::{
+        Entity,
+        decision::DecisionTree,
+        genome::Genome,
+        instinct::Behaviour,
+        intent::Intent,
+        lifestate::LifeState,
+        perception::Perception,
+        species::{Diet, Species},
+    },
+};
+use std::rc::Rc;
+
+/// Kararlarını hazır bir karar ağacı ile veren canlı
+///
+/// Otçul, etçil ve hepçil türler aynı canlıdır, sadece ağaçları ve
+/// genomları farklıdır. Aynı türden canlılar ve yavruları ağacı paylaşır.
+pub struct TreeEntity {
+    pub life_state: LifeState,
+    /// Kayıtlı tür kimliği
+    pub species: Species,
+    /// Hangi yemeklerin yenebileceği
+    pub diet: Diet,
+    /// İçgüdü eşikleri
+    pub behaviour: Behaviour,
+    /// Karar ağacı, aynı türden canlılar paylaşır
+    pub tree: Rc<DecisionTree>,
+}
+
+impl TreeEntity {
+    /// Beslenme biçimine göre hazır ağaç ile canlı oluştur
+    pub fn new(life_state: LifeState, species: Species, diet: Diet, behaviour: Behaviour) -> Self {
+        Self {
+            life_state,
+            species,
+            diet,
+            behaviour,
+            tree: Rc::new(DecisionTree::for_diet(diet)),
+        }
+    }
+
+    /// Hazır karar ağacı yerine verilen ağacı kullan
+    pub fn with_tree(mut self, tree: Rc<DecisionTree>) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Gömülü `config/species.cfg` içindeki hazır türden yetişkin canlı
+    /// Tür kimliği `SpeciesRegistry::default` ile aynıdır.
+    pub fn preset(name: &str) -> Self {
+        let (index, config) = default_species_configs()
+            .iter()
+            .enumerate()
+            .find(|(_, config)| config.info.name == name)
+            .unwrap_or_else(|| panic!("gömülü species.cfg içinde [{}] yok", name));
+        Self::new(
+            LifeState::new(config.info.genome.clone()),
+            Species(index),
+            config.info.diet,
+            config.behaviour,
+        )
+    }
+}
+
+impl Entity for TreeEntity {
+    fn life(&self) -> &LifeState {
+        &self.life_state
+    }
+
+    fn life_mut(&mut self) -> &mut LifeState {
+        &mut self.life_state
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn behaviour(&self) -> Behaviour {
+        self.behaviour
+    }
+
+    fn make_intent(&self, perception: Perception) -> Intent {
+        self.tree
+            .decide(&self.life_state, &perception, self.species, &self.behaviour)
+    }
+
+    fn tick(&mut self) {
+        self.life_state.tick();
+    }
+
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+        let genome = self.life_state.genome.inherit(partner);
+        Box::new(TreeEntity {
+            life_state: LifeState::newborn(genome),
+            species: self.species,
+            diet: self.diet,
+            behaviour: self.behaviour,
+            tree: self.tree.clone(),
+        })
+    }
+}
@@ -0,0 +1,40 @@
+/// Büyüme ve yaşlanma eğrisi, türe göre ayarlanır
+///
+/// Yavru yetişkin boyunun `juvenile` yüzdesi ile doğar ve yedikçe büyür.
+/// Canlının gücü (yüzde) ise yaşla değişir: ömrünün `onset` yüzdesine kadar
+/// tam güçtedir. Sonra her tick güç kaybeder, `max_age`de `decline` kadarını
+/// yitirmiş olur ve aynı hızla düşmeye devam eder.
+/// Hız, görüş, en yüksek can ve doğurganlık bu güçle ölçeklenir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ageing {
+    /// Doğumdaki boy, yetişkin boyunun yüzdesi
+    pub juvenile: usize,
+    /// Yaşlanmanın başladığı yaş, `max_age`in yüzdesi
+    pub onset: usize,
+    /// `max_age`e gelindiğinde kaybedilen güç (yüzde)
+    pub decline: usize,
+}
+
+impl Default for Ageing {
+    fn default() -> Self {
+        Self {
+            juvenile: 60,
+            onset: 60,
+            decline: 50,
+        }
+    }
+}
+
+impl Ageing {
+    /// Yaşa göre güç (0..=100)
+    pub fn vitality(&self, age: usize, maturity_age: usize, max_age: usize) -> usize {
+        // Yaşlılık: başlangıçtan itibaren doğrusal düşüş, max_age sonrasında da sürer
+        let onset = (max_age * self.onset / 100).max(maturity_age);
+        if age <= onset {
+            return 100;
+        }
+        let span = max_age.saturating_sub(onset).max(1);
+        let lost = (age - onset) * self.decline / span;
+        100usize.saturating_sub(lost)
+    }
+}
@@ -0,0 +1,926 @@
+use crate::{
+    entity::{
+        instinct::{Behaviour, Instinct, InstinctDecision, InstinctEvaluator},
+        intent::Intent,
+        lifestate::LifeState,
+        memory::MemoryKind,
+        perception::{PerceivedEntity, Perception, Relation},
+        signal::SignalKind,
+        species::{Diet, Species},
+    },
+    gen_range,
+    map::movement::{DIRECTION_ARRAY, Direction, Position, Steps},
+};
+
+/// Dibindeki yemek en az bu kadarsa yemek çağrısı yapılır
+pub const FOOD_CALL_AMOUNT: usize = 5;
+
+/// Öğrenen canlıların (Q-learning, sinir ağı) seçebileceği eylem sayısı
+pub const ACTION_COUNT: usize = 9;
+
+/// Eylemlerin isimleri, `action_node` sırası ile aynıdır
+pub const ACTION_NAMES: [&str; ACTION_COUNT] = [
+    "seek_food",
+    "seek_water",
+    "fight",
+    "flee",
+    "chase_prey",
+    "find_mate",
+    "explore",
+    "sleep",
+    "idle",
+];
+
+/// Eylemin karar ağacı karşılığı
+/// Eylemler hazır düğümlerdir, uygulanamazsa canlı bekler.
+pub fn action_node(diet: Diet, action: usize) -> Node {
+    match action {
+        0 => Node::SeekFood {
+            diet,
+            max_steps: None,
+        },
+        1 => Node::SeekWater,
+        2 => Node::Fight,
+        3 => Node::Flee,
+        4 => Node::ChasePrey,
+        5 => Node::FindMate,
+        6 => Node::Explore,
+        7 => Node::Sleep,
+        _ => Node::Idle,
+    }
+}
+
+/// Karar ağacının bir düğümü değerlendirirken gördüğü her şey
+pub struct DecisionContext<'a> {
+    pub life: &'a LifeState,
+    pub perception: &'a Perception,
+    pub species: Species,
+    /// Türün davranış ağırlıkları
+    pub behaviour: Behaviour,
+    /// İçgüdü değerlendirmesinin sonucu
+    pub decision: InstinctDecision,
+}
+
+impl<'a> DecisionContext<'a> {
+    /// İçgüdüleri değerlendirerek bağlam oluştur
+    pub fn new(
+        life: &'a LifeState,
+        perception: &'a Perception,
+        species: Species,
+        behaviour: &Behaviour,
+    ) -> Self {
+        Self {
+            life,
+            perception,
+            species,
+            behaviour: *behaviour,
+            decision: InstinctEvaluator::evaluate_with(life, perception, species, behaviour),
+        }
+    }
+}
+
+/// Davranış ağacı düğümü
+///
+/// Her düğüm ya bir niyet üretir ya da `None` döner.
+/// `None` dönen düğüm "uygulanamadı" demektir, `Selector` sıradakine geçer.
+/// Ağaçlar veri olarak kurulur, canlılar aynı düğümleri farklı sırayla kullanabilir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// Çocukları sırayla dener, ilk üretilen niyeti döndürür
+    Selector(Vec<Node>),
+    /// Baskın içgüdü listedekilerden biriyse çocuğu çalıştırır
+    When(Vec<Instinct>, Box<Node>),
+    /// Beslenme biçimine uyan en yakın yemeğe git ve ye
+    /// `max_steps` verilirse sadece o kadar yakındaki yemekler dikkate alınır.
+    SeekFood {
+        diet: Diet,
+        max_steps: Option<usize>,
+    },
+    /// En yakın suya git ve iç
+    SeekWater,
+    /// Görünürde yemek yoksa hatırlanan en yakın yemeğe yürü
+    RecallFood { diet: Diet },
+    /// Görünürde su yoksa hatırlanan en yakın suya yürü
+    RecallWater,
+    /// `range` içinde hatırlanan bir tehdit varsa ondan uzaklaş
+    AvoidThreats { range: usize },
+    /// En az ziyaret edilmiş yöne yürü (merak)
+    Explore,
+    /// Keşif ile bilineni kullanma arasında seçim yapar
+    /// `curiosity` yüzdesi ihtimalle önce `explore`, yoksa önce `exploit` denenir.
+    Balance {
+        exploit: Box<Node>,
+        explore: Box<Node>,
+    },
+    /// Tehdit yenilebilecek durumdaysa saldır
+    Fight,
+    /// Tehditten kaç
+    Flee,
+    /// Başka türden en zayıf ve en yakın canlıyı kovala, dibindeyse saldır
+    ChasePrey,
+    /// Görünürdeki sürüsüyle birlikte avlan
+    /// Hedef, sürüden birinin saldırdığı av ya da sürünün hepsine en yakın avdır.
+    /// Canlı avın etrafında sürünün tutmadığı bir yana geçer, dibindeyse saldırır.
+    PackHunt,
+    /// Kendi türünden karşı cinsiyette bir eşe yaklaş, dibindeyse çiftleş
+    FindMate,
+    /// Sebebi varsa `range` menzilli çağrı gönder
+    /// Alarm: tehdit var ama henüz dipte değil. Eş: çiftleşme dürtüsü var, görünürde eş yok.
+    /// Yemek: dibinde en az `FOOD_CALL_AMOUNT` yemek var.
+    /// Aynı türden aynı çağrı zaten duyuluyorsa susar.
+    Call { kind: SignalKind, range: usize },
+    /// Kendi türünden gelen alarmın ters yönüne kaç
+    HeedAlarm,
+    /// Kendi türünden gelen çağrının kaynağına yürü, eş çağrısında sadece karşı cinsiyet
+    FollowCall { kind: SignalKind },
+    /// Yavru, görünürdeki ebeveyninden `distance` adımdan fazla uzaklaşmaz
+    FollowParent { distance: usize },
+    /// Görünürdeki bir yavrusuna `range` adım yaklaşan başka türden canlıya saldır
+    DefendOffspring { range: usize },
+    /// Kendi türünden komşularla sürü hâlinde bir adım at, tehdit altındaysa koş
+    /// Yön; merkeze yaklaşma, dibindekilerden uzaklaşma ve sürünün yönüne
+    /// uyma kuvvetlerinin türün ağırlıklarıyla toplanmasından seçilir.
+    Flock,
+    /// Hızı kadar rastgele adım at
+    Wander,
+    /// Yorgunluğa göre uyu
+    Sleep,
+    /// Bir tick bekle
+    Idle,
+}
+
+impl Node {
+    /// Baskın içgüdü listedekilerden biriyse çocuğu çalıştıran düğüm
+    pub fn when(instincts: &[Instinct], child: Node) -> Node {
+        Node::When(instincts.to_vec(), Box::new(child))
+    }
+
+    /// Keşif/kullanma dengesi kuran düğüm
+    pub fn balance(exploit: Node, explore: Node) -> Node {
+        Node::Balance {
+            exploit: Box::new(exploit),
+            explore: Box::new(explore),
+        }
+    }
+
+    /// Düğümü değerlendir
+    pub fn run(&self, ctx: &DecisionContext) -> Option<Intent> {
+        let life = ctx.life;
+        let perception = ctx.perception;
+        match self {
+            Node::Selector(children) => children.iter().find_map(|child| child.run(ctx)),
+            Node::When(instincts, child) => {
+                if instincts.contains(&ctx.decision.instinct) {
+                    child.run(ctx)
+                } else {
+                    None
+                }
+            }
+            Node::SeekFood { diet, max_steps } => {
+                if life.is_energy_full() {
+                    return None;
+                }
+                let food = perception
+                    .foods
+                    .iter()
+                    .filter(|f| {
+                        if f.is_corpse {
+                            diet.eats_meat()
+                        } else {
+                            diet.eats_plants()
+                        }
+                    })
+                    .filter(|f| max_steps.is_none_or(|max| f.steps.len() <= max))
+                    .min_by_key(|f| (f.steps.len(), usize::MAX - f.amount))?;
+                Some(Intent::Eat {
+                    at: food.steps.clone(),
+                    corpse_id: food.corpse_id,
+                })
+            }
+            Node::SeekWater => {
+                if life.is_water_full() {
+                    return None;
+                }
+                let water = perception
+                    .waters
+                    .iter()
+                    .min_by_key(|w| (w.steps.len(), usize::MAX - w.amount))?;
+                Some(Intent::Drink {
+                    at: water.steps.clone(),
+                })
+            }
+            Node::RecallFood { diet } => {
+                if life.is_energy_full() {
+                    return None;
+                }
+                let place = life.memory.nearest(perception.origin, |kind| match kind {
+                    MemoryKind::Plant => diet.eats_plants(),
+                    MemoryKind::Carcass => diet.eats_meat(),
+                    _ => false,
+                })?;
+                walk_toward(perception.origin, place.pos, life.speed())
+            }
+            Node::RecallWater => {
+                if life.is_water_full() {
+                    return None;
+                }
+                let place = life
+                    .memory
+                    .nearest(perception.origin, |kind| kind == MemoryKind::Water)?;
+                walk_toward(perception.origin, place.pos, life.speed())
+            }
+            Node::AvoidThreats { range } => {
+                let threat = life
+                    .memory
+                    .nearest(perception.origin, |kind| {
+                        matches!(kind, MemoryKind::Threat(_))
+                    })
+                    .filter(|threat| perception.origin.distance_to(threat.pos) <= *range)?;
+                let away = perception.origin.direction_to(threat.pos)?.opposite();
+                Some(Intent::Move {
+                    steps: Steps::new(vec![away; life.speed().max(1)]),
+                })
+            }
+            Node::Explore => {
+                // Her yön, gidilebilecek hücrelerin yenilik toplamı ile puanlanır
+                let speed = life.speed().max(1);
+                let (dir, reach, _) = DIRECTION_ARRAY
+                    .iter()
+                    .filter_map(|dir| {
+                        let reach = speed.min(*perception.directions.get(dir)? as usize);
+                        if reach == 0 {
+                            return None;
+                        }
+                        let mut pos = perception.origin;
+                        let mut score = 0;
+                        for _ in 0..reach {
+                            pos = pos + *dir;
+                            score += life.memory.novelty(pos);
+                        }
+                        // Eşit yönler arasında rastgele seçim
+                        Some((*dir, reach, score * 10 + gen_range(0, 9) as usize))
+                    })
+                    .max_by_key(|(_, _, score)| *score)?;
+                Some(Intent::Move {
+                    steps: Steps::new(vec![dir; reach]),
+                })
+            }
+            Node::Balance { exploit, explore } => {
+                if gen_range(1, 100) as usize <= ctx.behaviour.curiosity {
+                    explore.run(ctx).or_else(|| exploit.run(ctx))
+                } else {
+                    exploit.run(ctx).or_else(|| explore.run(ctx))
+                }
+            }
+            Node::Fight => {
+                // Yavrular dövüşmez, ebeveynlerine güvenir
+                if life.is_juvenile() {
+                    return None;
+                }
+                let threat = ctx.decision.threat.filter(|threat| threat.can_win)?;
+                Some(Intent::Attack {
+                    target_id: threat.target_id,
+                })
+            }
+            Node::Flee => {
+                let threat = ctx.decision.threat?;
+                Some(Intent::Flee {
+                    target_id: threat.target_id,
+                })
+            }
+            Node::ChasePrey => {
+                if life.is_juvenile() {
+                    return None;
+                }
+                let prey = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.species != ctx.species)
+                    .min_by_key(|e| (e.steps.len(), e.power))?;
+                if prey.steps.len() <= 1 {
+                    return Some(Intent::Attack { target_id: prey.id });
+                }
+                Some(Intent::Move {
+                    steps: prey.steps.clone(),
+                })
+            }
+            Node::PackHunt => {
+                if life.is_juvenile() {
+                    return None;
+                }
+                let origin = perception.origin;
+                let pack: Vec<&PerceivedEntity> = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.species == ctx.species)
+                    .collect();
+                if pack.is_empty() {
+                    return None;
+                }
+                let positions: Vec<Position> = pack
+                    .iter()
+                    .map(|mate| entity_position(origin, mate))
+                    .collect();
+                // Sürü birlikte, tek başına yenemeyeceği avı da alabilir
+                let strength = life.combat_power() * (pack.len() + 1);
+                let preys: Vec<&PerceivedEntity> = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.species != ctx.species && e.power <= strength)
+                    .collect();
+                let shared = pack
+                    .iter()
+                    .filter_map(|mate| mate.target)
+                    .find_map(|target| preys.iter().find(|prey| prey.id == target));
+                let prey = shared.or_else(|| {
+                    preys.iter().min_by_key(|prey| {
+                        let pos = entity_position(origin, prey);
+                        let distance: usize = positions.iter().map(|m| m.distance_to(pos)).sum();
+                        (origin.distance_to(pos) + distance, prey.power, prey.id)
+                    })
+                })?;
+                if prey.steps.len() <= 1 {
+                    return Some(Intent::Attack { target_id: prey.id });
+                }
+
+                // Kuşatma: sürünün tutmadığı yönlerden avın dibindeki en yakın hücre
+                let prey_pos = entity_position(origin, prey);
+                let taken: Vec<Direction> = positions
+                    .iter()
+                    .filter_map(|pos| prey_pos.direction_to(*pos))
+                    .collect();
+                let occupied: Vec<Position> = perception
+                    .entities
+                    .iter()
+                    .map(|e| entity_position(origin, e))
+                    .collect();
+                let spot = DIRECTION_ARRAY
+                    .iter()
+                    .filter(|dir| !taken.contains(dir))
+                    .map(|dir| prey_pos + *dir)
+                    .filter(|cell| !occupied.contains(cell))
+                    .min_by_key(|cell| (origin.distance_to(*cell), cell.x, cell.y))
+                    .unwrap_or(prey_pos);
+                if spot == prey_pos {
+                    return approach(prey, life.speed());
+                }
+                walk_toward(origin, spot, life.speed())
+            }
+            Node::FindMate => {
+                let mate = perception.entities.iter().find(|e| {
+                    e.species == ctx.species && e.sex != life.sex && !life.memory.has_refused(e.id)
+                })?;
+                if mate.steps.len() <= 1 {
+                    return Some(Intent::Mate { target_id: mate.id });
+                }
+                // Çiftleşmek için hedefe yaklaş (tek adım)
+                let mut one_step = Steps::empty();
+                if let Some(first) = mate.steps.0.first() {
+                    one_step.0.push(*first);
+                }
+                Some(Intent::Move { steps: one_step })
+            }
+            Node::Call { kind, range } => {
+                let heard = perception
+                    .signals
+                    .iter()
+                    .any(|s| s.kind == *kind && s.species == ctx.species);
+                if heard {
+                    return None;
+                }
+                let reason = match kind {
+                    SignalKind::Alarm => ctx.decision.threat.is_some_and(|threat| {
+                        perception
+                            .entities
+                            .iter()
+                            .any(|e| e.id == threat.target_id && e.steps.len() > 1)
+                    }),
+                    SignalKind::Mating => {
+                        ctx.decision.instinct == Instinct::Mating
+                            && !perception
+                                .entities
+                                .iter()
+                                .any(|e| e.species == ctx.species && e.sex != life.sex)
+                    }
+                    SignalKind::Food => perception
+                        .foods
+                        .iter()
+                        .any(|f| f.steps.len() <= 1 && f.amount >= FOOD_CALL_AMOUNT),
+                };
+                if !reason {
+                    return None;
+                }
+                Some(Intent::Signal {
+                    kind: *kind,
+                    range: *range,
+                })
+            }
+            Node::HeedAlarm => {
+                let alarm = perception
+                    .signals
+                    .iter()
+                    .filter(|s| !s.own && s.kind == SignalKind::Alarm && s.species == ctx.species)
+                    .min_by_key(|s| s.distance)?;
+                let away = perception.origin.direction_to(alarm.pos)?.opposite();
+                Some(Intent::Move {
+                    steps: Steps::new(vec![away; life.speed()]),
+                })
+            }
+            Node::FollowCall { kind } => {
+                let call = perception
+                    .signals
+                    .iter()
+                    .filter(|s| !s.own && s.kind == *kind && s.species == ctx.species)
+                    // Eş çağrısına sadece karşı cinsiyet gider
+                    .filter(|s| *kind != SignalKind::Mating || s.sex != life.sex)
+                    .min_by_key(|s| s.distance)?;
+                walk_toward(perception.origin, call.pos, life.speed())
+            }
+            Node::FollowParent { distance } => {
+                if !life.is_juvenile() {
+                    return None;
+                }
+                let parent = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.relation == Relation::Parent)
+                    .min_by_key(|e| e.steps.len())?;
+                if parent.steps.len() <= *distance {
+                    return None;
+                }
+                approach(parent, life.speed())
+            }
+            Node::DefendOffspring { range } => {
+                if life.is_juvenile() {
+                    return None;
+                }
+                let position = |e: &PerceivedEntity| entity_position(perception.origin, e);
+                let offspring: Vec<Position> = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.relation == Relation::Offspring)
+                    .map(position)
+                    .collect();
+                if offspring.is_empty() {
+                    return None;
+                }
+                // Ebeveyn kendinden iki kat güçlü olana karşı durmaz
+                let power = life.combat_power();
+                let threat = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.species != ctx.species && e.power <= power * 2)
+                    .filter(|e| {
+                        let pos = position(e);
+                        offspring
+                            .iter()
+                            .any(|child| child.distance_to(pos) <= *range)
+                    })
+                    .min_by_key(|e| e.steps.len())?;
+                if threat.steps.len() <= 1 {
+                    return Some(Intent::Attack {
+                        target_id: threat.id,
+                    });
+                }
+                approach(threat, life.speed())
+            }
+            Node::Flock => {
+                let behaviour = ctx.behaviour;
+                let herd: Vec<(Position, &PerceivedEntity)> = perception
+                    .entities
+                    .iter()
+                    .filter(|e| e.species == ctx.species)
+                    .map(|e| (entity_position(perception.origin, e), e))
+                    .collect();
+                if herd.is_empty() {
+                    return None;
+                }
+                let origin = perception.origin;
+                let count = herd.len() as isize;
+                let (mut x, mut y) = (0, 0);
+
+                // Merkeze yaklaşma
+                let center = Position::new(
+                    herd.iter().map(|(pos, _)| pos.x).sum::<isize>() / count,
+                    herd.iter().map(|(pos, _)| pos.y).sum::<isize>() / count,
+                );
+                if let Some(dir) = origin.direction_to(center) {
+                    let (dx, dy) = dir.offset();
+                    x += dx * behaviour.cohesion as isize;
+                    y += dy * behaviour.cohesion as isize;
+                }
+                // Dibindekilerden uzaklaşma
+                for (pos, mate) in &herd {
+                    if mate.steps.len() > 1 {
+                        continue;
+                    }
+                    if let Some(dir) = origin.direction_to(*pos) {
+                        let (dx, dy) = dir.opposite().offset();
+                        x += dx * behaviour.separation as isize;
+                        y += dy * behaviour.separation as isize;
+                    }
+                }
+                // Sürünün yönüne uyma
+                let (hx, hy) = herd
+                    .iter()
+                    .filter_map(|(_, mate)| mate.heading)
+                    .map(|dir| dir.offset())
+                    .fold((0, 0), |(ax, ay), (dx, dy)| (ax + dx, ay + dy));
+                x += hx.signum() * behaviour.alignment as isize;
+                y += hy.signum() * behaviour.alignment as isize;
+
+                // Kuvvete en uygun, gidilebilir yön
+                let dir = DIRECTION_ARRAY
+                    .iter()
+                    .filter(|dir| perception.directions.get(dir).is_some_and(|d| *d > 0))
+                    .map(|dir| {
+                        let (dx, dy) = dir.offset();
+                        (*dir, dx * x + dy * y)
+                    })
+                    .filter(|(_, score)| *score > 0)
+                    .max_by_key(|(_, score)| *score)?
+                    .0;
+                let reach = if ctx.decision.threat.is_some() {
+                    life.speed().min(perception.directions[&dir] as usize)
+                } else {
+                    1
+                };
+                Some(Intent::Move {
+                    steps: Steps::new(vec![dir; reach]),
+                })
+            }
+            Node::Wander => {
+                let mut steps = Steps::empty();
+                for _ in 0..life.speed() {
+                    steps.0.push(DIRECTION_ARRAY[gen_range(0, 7isize) as usize])
+                }
+                Some(Intent::Move { steps })
+            }
+            Node::Sleep => Some(Intent::Sleep {
+                duration: life.sleep_duration(),
+            }),
+            Node::Idle => Some(Intent::Idle { duration: 1 }),
+        }
+    }
+}
+
+/// Hedefe doğru hızı kadar düz adım
+/// Yol bulma yapılmaz, engelde World hareketi keser.
+fn walk_toward(from: Position, to: Position, speed: usize) -> Option<Intent> {
+    let mut steps = Steps::empty();
+    let mut pos = from;
+    for _ in 0..speed {
+        let Some(dir) = pos.direction_to(to) else {
+            break;
+        };
+        steps.0.push(dir);
+        pos = pos + dir;
+    }
+    if steps.is_empty() {
+        return None;
+    }
+    Some(Intent::Move { steps })
+}
+
+/// Algılanan canlının konumu
+fn entity_position(origin: Position, entity: &PerceivedEntity) -> Position {
+    entity.steps.iter().fold(origin, |pos, dir| pos + *dir)
+}
+
+/// Algılanan canlının yanına kadar hızı kadar adım
+/// Son adım canlının kendi hücresi olduğundan atılmaz.
+fn approach(target: &PerceivedEntity, speed: usize) -> Option<Intent> {
+    let reach = target.steps.len().saturating_sub(1).min(speed);
+    if reach == 0 {
+        return None;
+    }
+    Some(Intent::Move {
+        steps: Steps::new(target.steps.0[..reach].to_vec()),
+    })
+}
+
+/// Bir canlının karar ağacı
+/// Önce içgüdüler değerlendirilir, sonra kök düğüm çalışır.
+/// Hiçbir düğüm niyet üretmezse canlı bekler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    pub root: Node,
+}
+
+impl DecisionTree {
+    pub fn new(root: Node) -> Self {
+        Self { root }
+    }
+
+    /// Beslenme biçimine göre hazır ağaç
+    pub fn for_diet(diet: Diet) -> Self {
+        match diet {
+            Diet::Herbivore => Self::herbivore(),
+            Diet::Carnivore => Self::carnivore(),
+            Diet::Omnivore => Self::omnivore(),
+        }
+    }
+
+    /// Otçul: kazanamayacağı tehditte sürüsüyle birlikte koşar, sürüsü görünmüyorsa
+    /// önce alarm verip sonra rastgele uzaklaşır, leş yemez,
+    /// boşta kalınca avcılardan uzak durur ve sürüsüne katılır
+    pub fn herbivore() -> Self {
+        Self::new(Node::Selector(vec![
+            Node::DefendOffspring { range: 2 },
+            Node::when(
+                &[Instinct::Threat],
+                Node::Selector(vec![
+                    Node::Fight,
+                    // Alarm için durmak sürüden geri kalmaktır, yalnızken çağırır
+                    Node::Flock,
+                    Node::Call {
+                        kind: SignalKind::Alarm,
+                        range: 8,
+                    },
+                    Node::Wander,
+                ]),
+            ),
+            // Sürüden gelen alarm, avcı görünmese de kaçırır
+            Node::HeedAlarm,
+            Node::FollowParent { distance: 1 },
+            Node::when(
+                &[Instinct::Survival, Instinct::Hunger],
+                Node::Selector(vec![
+                    Node::SeekFood {
+                        diet: Diet::Herbivore,
+                        max_steps: None,
+                    },
+                    Node::FollowCall {
+                        kind: SignalKind::Food,
+                    },
+                    Node::balance(
+                        Node::RecallFood {
+                            diet: Diet::Herbivore,
+                        },
+                        Node::Explore,
+                    ),
+                    Node::Wander,
+                ]),
+            ),
+            // Boştayken avcı görülen yerlerden uzak durur, sürüsüne katılır
+            Node::when(
+                &[Instinct::Idle],
+                Node::Selector(vec![
+                    Node::AvoidThreats { range: 8 },
+                    Node::Call {
+                        kind: SignalKind::Food,
+                        range: 6,
+                    },
+                    Node::Flock,
+                ]),
+            ),
+            Self::common_needs(),
+        ]))
+    }
+
+    /// Etçil: leş yoksa sürüsüyle ya da tek başına avlanır, boşta kalınca dibindeki leşi bitirir
+    pub fn carnivore() -> Self {
+        Self::new(Node::Selector(vec![
+            Node::DefendOffspring { range: 2 },
+            Node::when(
+                &[Instinct::Threat],
+                Node::Selector(vec![Node::Fight, Node::Flee, Node::Idle]),
+            ),
+            Node::FollowParent { distance: 1 },
+            Node::when(
+                &[Instinct::Survival, Instinct::Hunger],
+                Node::Selector(vec![
+                    Node::SeekFood {
+                        diet: Diet::Carnivore,
+                        max_steps: None,
+                    },
+                    Node::PackHunt,
+                    Node::ChasePrey,
+                    Node::balance(
+                        Node::RecallFood {
+                            diet: Diet::Carnivore,
+                        },
+                        Node::Explore,
+                    ),
+                    Node::Wander,
+                ]),
+            ),
+            Node::when(
+                &[Instinct::Idle],
+                Node::SeekFood {
+                    diet: Diet::Carnivore,
+                    max_steps: Some(1),
+                },
+            ),
+            Self::common_needs(),
+        ]))
+    }
+
+    /// Hepçil: önce yemek, yoksa av
+    pub fn omnivore() -> Self {
+        Self::new(Node::Selector(vec![
+            Node::DefendOffspring { range: 2 },
+            Node::when(
+                &[Instinct::Threat],
+                Node::Selector(vec![Node::Fight, Node::Flee, Node::Idle]),
+            ),
+            Node::FollowParent { distance: 1 },
+            Node::when(
+                &[Instinct::Survival, Instinct::Hunger],
+                Node::Selector(vec![
+                    Node::SeekFood {
+                        diet: Diet::Omnivore,
+                        max_steps: None,
+                    },
+                    Node::ChasePrey,
+                    Node::balance(
+                        Node::RecallFood {
+                            diet: Diet::Omnivore,
+                        },
+                        Node::Explore,
+                    ),
+                    Node::Wander,
+                ]),
+            ),
+            Self::common_needs(),
+        ]))
+    }
+
+    /// Tüm hazır canlıların paylaştığı su, uyku ve üreme dalları
+    fn common_needs() -> Node {
+        Node::Selector(vec![
+            Node::when(
+                &[Instinct::Thirst],
+                Node::Selector(vec![
+                    Node::SeekWater,
+                    Node::balance(Node::RecallWater, Node::Explore),
+                    Node::Idle,
+                ]),
+            ),
+            Node::when(&[Instinct::Fatigue], Node::Sleep),
+            Node::when(
+                &[Instinct::Mating],
+                Node::Selector(vec![
+                    Node::FindMate,
+                    Node::FollowCall {
+                        kind: SignalKind::Mating,
+                    },
+                    Node::Call {
+                        kind: SignalKind::Mating,
+                        range: 10,
+                    },
+                    Node::Idle,
+                ]),
+            ),
+            Node::Idle,
+        ])
+    }
+
+    /// İçgüdüleri değerlendirip ağacı çalıştır
+    pub fn decide(
+        &self,
+        life: &LifeState,
+        perception: &Perception,
+        species: Species,
+        behaviour: &Behaviour,
+    ) -> Intent {
+        let ctx = DecisionContext::new(life, perception, species, behaviour);
+        self.root.run(&ctx).unwrap_or(Intent::Idle { duration: 1 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        creatures::config::default_species,
+        entity::{instinct::ThreatAssessment, memory::MemoryKind, reproduction::Sex},
+    };
+
+    fn life() -> LifeState {
+        LifeState::new(default_species("herbivore").info.genome.clone())
+    }
+
+    /// İçgüdüsü ve tehdidi elle verilmiş bağlam
+    fn context<'a>(
+        life: &'a LifeState,
+        perception: &'a Perception,
+        instinct: Instinct,
+        threat: Option<ThreatAssessment>,
+    ) -> DecisionContext<'a> {
+        DecisionContext {
+            life,
+            perception,
+            species: Species::HERBIVORE,
+            behaviour: Behaviour::default(),
+            decision: InstinctDecision { instinct, threat },
+        }
+    }
+
+    #[test]
+    fn selector_falls_through_to_first_intent() {
+        let (life, perception) = (life(), Perception::empty());
+        let ctx = context(&life, &perception, Instinct::Idle, None);
+        // Su görünmüyor ve tehdit yok, ilk iki düğüm uygulanamaz
+        let node = Node::Selector(vec![Node::SeekWater, Node::Fight, Node::Sleep, Node::Idle]);
+        assert!(matches!(node.run(&ctx), Some(Intent::Sleep { .. })));
+        assert_eq!(Node::Selector(vec![Node::Fight]).run(&ctx), None);
+        assert_eq!(Node::Selector(Vec::new()).run(&ctx), None);
+    }
+
+    #[test]
+    fn when_runs_child_only_for_listed_instincts() {
+        let (life, perception) = (life(), Perception::empty());
+        let node = Node::when(&[Instinct::Thirst, Instinct::Hunger], Node::Idle);
+        for (instinct, runs) in [
+            (Instinct::Thirst, true),
+            (Instinct::Hunger, true),
+            (Instinct::Idle, false),
+            (Instinct::Threat, false),
+        ] {
+            let ctx = context(&life, &perception, instinct, None);
+            assert_eq!(node.run(&ctx).is_some(), runs, "{:?}", instinct);
+        }
+    }
+
+    #[test]
+    fn fights_winnable_threat_and_flees_otherwise() {
+        let (life, perception) = (life(), Perception::empty());
+        let node = Node::Selector(vec![Node::Fight, Node::Flee]);
+        let threat = |can_win| {
+            Some(ThreatAssessment {
+                target_id: 9,
+                can_win,
+            })
+        };
+
+        let ctx = context(&life, &perception, Instinct::Threat, threat(true));
+        assert_eq!(node.run(&ctx), Some(Intent::Attack { target_id: 9 }));
+        let ctx = context(&life, &perception, Instinct::Threat, threat(false));
+        assert_eq!(node.run(&ctx), Some(Intent::Flee { target_id: 9 }));
+        let ctx = context(&life, &perception, Instinct::Threat, None);
+        assert_eq!(node.run(&ctx), None);
+    }
+
+    #[test]
+    fn avoids_remembered_threat_by_moving_away() {
+        let mut life = life();
+        life.memory
+            .remember(MemoryKind::Threat(Species::CARNIVORE), Position::new(3, 0));
+        let perception = Perception::empty();
+        let ctx = context(&life, &perception, Instinct::Idle, None);
+
+        let Some(Intent::Move { steps }) = (Node::AvoidThreats { range: 5 }).run(&ctx) else {
+            panic!("tehditten uzaklaşmadı");
+        };
+        assert_eq!(steps.len(), life.speed().max(1));
+        assert!(steps.iter().all(|dir| *dir == Direction::Left));
+        // Menzil dışındaki tehdit umursanmaz
+        assert_eq!((Node::AvoidThreats { range: 2 }).run(&ctx), None);
+    }
+
+    #[test]
+    fn herbivore_runs_with_herd_and_alarms_only_when_alone() {
+        let tree = DecisionTree::herbivore();
+        let life = life();
+        let threat = Some(ThreatAssessment {
+            target_id: 9,
+            can_win: false,
+        });
+        let mut perception = Perception::empty();
+        for dir in DIRECTION_ARRAY {
+            perception.directions.insert(dir, 3);
+        }
+        perception.add_entity(
+            9,
+            Species::CARNIVORE,
+            life.combat_power() * 4,
+            Sex::Male,
+            Relation::Stranger,
+            Steps::new(vec![Direction::Right; 3]),
+        );
+
+        // Yalnız otçul alarm verir
+        let ctx = context(&life, &perception, Instinct::Threat, threat);
+        assert_eq!(
+            tree.root.run(&ctx),
+            Some(Intent::Signal {
+                kind: SignalKind::Alarm,
+                range: 8,
+            })
+        );
+
+        // Sürüsü görünüyorsa durmadan onunla koşar
+        perception.add_entity(
+            4,
+            Species::HERBIVORE,
+            life.combat_power(),
+            Sex::Female,
+            Relation::Stranger,
+            Steps::new(vec![Direction::Left; 2]),
+        );
+        let mut ctx = context(&life, &perception, Instinct::Threat, threat);
+        ctx.behaviour = default_species("herbivore").behaviour;
+        assert!(matches!(tree.root.run(&ctx), Some(Intent::Move { .. })));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{entity::ageing::Ageing, gen_range};
+
+/// Bir genin mutasyona uğrama ihtimali (yüzde)
+pub const MUTATION_CHANCE: isize = 10;
+
+/// Mutasyonun geni en fazla ne kadar değiştirebileceği (yüzde)
+pub const MUTATION_SPREAD: usize = 10;
+
+/// Mutasyonun bir sinir ağı ağırlığını en fazla ne kadar değiştirebileceği (binde)
+pub const WEIGHT_MUTATION_SPREAD: isize = 200;
+
+/// Gen sayısı
+pub const GENE_COUNT: usize = 14;
+
+/// Genlerin isimleri, `Genome::genes` sırası ile aynıdır
+pub const GENE_NAMES: [&str; GENE_COUNT] = [
+    "max_age",
+    "max_health",
+    "max_energy",
+    "max_water",
+    "maturity_age",
+    "vision_range",
+    "speed",
+    "attack",
+    "defense",
+    "agility",
+    "max_fatigue",
+    "gestation_period",
+    "litter_size",
+    "reproduction_cooldown",
+];
+
+/// Canlının kalıtsal (ömür boyu sabit) özellikleri
+/// Yavru, iki ebeveynin genomunun çaprazlanması ve
+/// ardından mutasyona uğramasıyla oluşur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Genome {
+    /// Maksimum yaş (tick cinsinden)
+    pub max_age: usize,
+
+    /// Maksimum can
+    pub max_health: usize,
+
+    /// Maksimum enerji
+    pub max_energy: usize,
+
+    /// Maksimum su
+    pub max_water: usize,
+
+    /// Üreme için minimum yaş
+    pub maturity_age: usize,
+
+    /// Canlının görüş açısı
+    pub vision_range: usize,
+
+    /// Tick başına maksimum hareket hakkı
+    pub speed: usize,
+
+    /// Saldırı gücü, verilen hasarı belirler
+    pub attack: usize,
+
+    /// Savunma, alınan hasarı azaltır
+    pub defense: usize,
+
+    /// Çeviklik, isabet ve kaçınma ihtimalini belirler
+    pub agility: usize,
+
+    /// Dayanılabilecek en yüksek yorgunluk
+    pub max_fatigue: usize,
+
+    /// Gebelik süresi (tick)
+    pub gestation_period: usize,
+
+    /// Bir doğumda dünyaya gelen en fazla yavru
+    pub litter_size: usize,
+
+    /// Çiftleştikten sonra tekrar üreyebilmek için beklenen süre (tick)
+    pub reproduction_cooldown: usize,
+
+    /// Sinir ağı ağırlıkları (binde bir hassasiyetle), sinir ağı ile
+    /// karar vermeyen canlılar için boştur. Diğer genler gibi kalıtılır.
+    pub weights: Vec<i32>,
+
+    /// Türün yaşlanma eğrisi, mutasyona uğramadan yavruya geçer
+    pub ageing: Ageing,
+}
+
+impl Genome {
+    /// Gen değerlerinden genom oluştur (`GENE_NAMES` sırası ile)
+    pub fn from_genes(genes: [usize; GENE_COUNT]) -> Genome {
+        let [
+            max_age,
+            max_health,
+            max_energy,
+            max_water,
+            maturity_age,
+            vision_range,
+            speed,
+            attack,
+            defense,
+            agility,
+            max_fatigue,
+            gestation_period,
+            litter_size,
+            reproduction_cooldown,
+        ] = genes;
+        Genome {
+            max_age,
+            max_health,
+            max_energy,
+            max_water,
+            maturity_age,
+            vision_range,
+            speed,
+            attack,
+            defense,
+            agility,
+            max_fatigue,
+            gestation_period,
+            litter_size,
+            reproduction_cooldown,
+            weights: Vec::new(),
+            ageing: Ageing::default(),
+        }
+    }
+
+    /// Gen değerleri (`GENE_NAMES` sırası ile)
+    pub fn genes(&self) -> [usize; GENE_COUNT] {
+        let mut copy = self.clone();
+        copy.genes_mut().map(|gene| *gene)
+    }
+
+    /// İsmi verilen genin değeri
+    pub fn gene(&self, name: &str) -> Option<usize> {
+        let index = GENE_NAMES.iter().position(|gene| *gene == name)?;
+        Some(self.genes()[index])
+    }
+
+    /// Genlere sırasıyla erişim (çaprazlama ve mutasyon için)
+    fn genes_mut(&mut self) -> [&mut usize; GENE_COUNT] {
+        [
+            &mut self.max_age,
+            &mut self.max_health,
+            &mut self.max_energy,
+            &mut self.max_water,
+            &mut self.maturity_age,
+            &mut self.vision_range,
+            &mut self.speed,
+            &mut self.attack,
+            &mut self.defense,
+            &mut self.agility,
+            &mut self.max_fatigue,
+            &mut self.gestation_period,
+            &mut self.litter_size,
+            &mut self.reproduction_cooldown,
+        ]
+    }
+
+    /// Tek noktalı değil, gen başına çaprazlama
+    /// Her gen iki ebeveynden rastgele birinden alınır.
+    /// Ağırlıklar da tek tek çaprazlanır; ağ boyutları farklıysa bu ebeveyninki kalır.
+    pub fn crossover(&self, other: &Genome) -> Genome {
+        let mut child = self.clone();
+        let mut donor = other.clone();
+        for (gene, from_other) in child.genes_mut().into_iter().zip(donor.genes_mut()) {
+            if gen_range(0, 1) == 1 {
+                *gene = *from_other;
+            }
+        }
+        if child.weights.len() == other.weights.len() {
+            for (weight, from_other) in child.weights.iter_mut().zip(&other.weights) {
+                if gen_range(0, 1) == 1 {
+                    *weight = *from_other;
+                }
+            }
+        }
+        child
+    }
+
+    /// Her gen `MUTATION_CHANCE` ihtimalle en fazla `MUTATION_SPREAD` kadar değişir
+    /// Genler en az 1 olarak kalır. Ağırlıklar `WEIGHT_MUTATION_SPREAD` kadar oynar.
+    pub fn mutate(&mut self) {
+        for gene in self.genes_mut() {
+            if gen_range(1, 100) > MUTATION_CHANCE {
+                continue;
+            }
+            let spread = (*gene * MUTATION_SPREAD / 100).max(1) as isize;
+            let delta = gen_range(-spread, spread);
+            *gene = (*gene as isize + delta).max(1) as usize;
+        }
+        for weight in &mut self.weights {
+            if gen_range(1, 100) > MUTATION_CHANCE {
+                continue;
+            }
+            *weight += gen_range(-WEIGHT_MUTATION_SPREAD, WEIGHT_MUTATION_SPREAD) as i32;
+        }
+    }
+
+    /// İki ebeveynden yavru genomu üret (çaprazlama + mutasyon)
+    pub fn inherit(&self, other: &Genome) -> Genome {
+        let mut child = self.crossover(other);
+        child.mutate();
+        child
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uniform(value: usize, weights: Vec<i32>) -> Genome {
+        let mut genome = Genome::from_genes([value; GENE_COUNT]);
+        genome.weights = weights;
+        genome
+    }
+
+    #[test]
+    fn crossover_takes_every_gene_from_a_parent() {
+        let mother = uniform(10, vec![1; 8]);
+        let father = uniform(20, vec![2; 8]);
+        for _ in 0..20 {
+            let child = mother.crossover(&father);
+            assert!(child.genes().iter().all(|gene| *gene == 10 || *gene == 20));
+            assert!(child.weights.iter().all(|w| *w == 1 || *w == 2));
+        }
+    }
+
+    #[test]
+    fn crossover_keeps_own_weights_when_sizes_differ() {
+        let mother = uniform(10, vec![1; 8]);
+        let father = uniform(20, vec![2; 3]);
+        assert_eq!(mother.crossover(&father).weights, vec![1; 8]);
+    }
+
+    #[test]
+    fn mutate_stays_within_spread_and_above_zero() {
+        for _ in 0..50 {
+            let mut genome = uniform(100, vec![0; 4]);
+            genome.mutate();
+            assert!(genome.genes().iter().all(|gene| (90..=110).contains(gene)));
+            assert!(
+                genome
+                    .weights
+                    .iter()
+                    .all(|w| w.unsigned_abs() <= WEIGHT_MUTATION_SPREAD as u32)
+            );
+
+            let mut tiny = uniform(1, Vec::new());
+            tiny.mutate();
+            assert!(tiny.genes().iter().all(|gene| *gene >= 1));
+        }
+    }
+
+    #[test]
+    fn inherit_copies_ageing_without_mutation() {
+        let mut mother = uniform(50, Vec::new());
+        mother.ageing = Ageing {
+            juvenile: 40,
+            onset: 70,
+            decline: 30,
+        };
+        let father = mother.clone();
+        assert_eq!(mother.inherit(&father).ageing, mother.ageing);
+    }
+
+    #[test]
+    fn gene_lookup_by_name() {
+        let genome = Genome::from_genes(std::array::from_fn(|i| i + 1));
+        assert_eq!(genome.gene("max_age"), Some(1));
+        assert_eq!(genome.gene("litter_size"), Some(GENE_COUNT - 1));
+        assert_eq!(genome.gene("reproduction_cooldown"), Some(GENE_COUNT));
+        assert_eq!(genome.gene("wings"), None);
+    }
+}
@@ -11,12 +11,65 @@ pub enum Instinct {
     Hunger,
     /// Susuzluk (su düşük).
     Thirst,
+    /// Yorgunluk (uyku gerekli).
+    Fatigue,
     /// Çiftleşme (üreme mümkün).
     Mating,
     /// Özel bir dürtü yok.
     Idle,
 }
 
+/// Tehdit algısı için varsayılan mesafe eşiği (adım sayısı)
+/// Türler `Behaviour::threat_range` ile değiştirir, uyuyanlar da bu mesafede uyanır.
+pub const THREAT_RANGE: usize = 2;
+
+/// Sürü güvenliğine en fazla kaç sürü üyesi katkı verir
+pub const HERD_LIMIT: usize = 8;
+
+/// Türe özgü davranış ağırlıkları
+/// İçgüdülerin hangi eşikte devreye gireceğini belirler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Behaviour {
+    /// Tehdit algısı için mesafe eşiği (adım sayısı)
+    pub threat_range: usize,
+    /// Cesaret (yüzde), kendi gücü bu oranla çarpılıp rakiple kıyaslanır
+    pub courage: usize,
+    /// Enerji, azami enerjinin bu yüzdesine inince açlık başlar
+    pub hunger: usize,
+    /// Su, azami suyun bu yüzdesine inince susuzluk başlar
+    pub thirst: usize,
+    /// Yorgunluk, azami yorgunluğun bu yüzdesine çıkınca uyku ihtiyacı başlar
+    pub fatigue: usize,
+    /// Merak (yüzde), görünürde kaynak yokken bilinen kaynak yerine
+    /// keşfedilmemiş yerleri seçme ihtimali
+    pub curiosity: usize,
+    /// Sürünün merkezine yaklaşma ağırlığı
+    pub cohesion: usize,
+    /// Dibindeki sürü üyelerinden uzaklaşma ağırlığı
+    pub separation: usize,
+    /// Sürünün hareket yönüne uyma ağırlığı
+    pub alignment: usize,
+    /// Görünen her sürü üyesinin cesarete kattığı yüzde (sayıdan güç)
+    pub herd_safety: usize,
+}
+
+impl Default for Behaviour {
+    fn default() -> Self {
+        Self {
+            threat_range: THREAT_RANGE,
+            courage: 100,
+            hunger: 25,
+            thirst: 25,
+            fatigue: 75,
+            curiosity: 30,
+            cohesion: 0,
+            separation: 0,
+            alignment: 0,
+            herd_safety: 0,
+        }
+    }
+}
+
 /// İçgüdü değerlendirme aracı.
 #[derive(Debug, Clone, Copy)]
 pub struct InstinctEvaluator;
@@ -40,14 +93,31 @@ impl InstinctEvaluator {
         perception: &Perception,
         own_species: Species,
     ) -> InstinctDecision {
-        // Tehdit algısı için mesafe eşiği (adım sayısı)
-        const THREAT_RANGE: usize = 2;
+        Self::evaluate_with(life, perception, own_species, &Behaviour::default())
+    }
 
-        let own_power = life.health + life.energy;
+    /// İçgüdü sıralamasını türün davranış ağırlıkları ile uygular.
+    pub fn evaluate_with(
+        life: &LifeState,
+        perception: &Perception,
+        own_species: Species,
+        behaviour: &Behaviour,
+    ) -> InstinctDecision {
+        // Sürü kalabalıklaştıkça tehdit daha az korkutucu görünür
+        let herd = perception
+            .entities
+            .iter()
+            .filter(|entity| entity.species == own_species)
+            .count()
+            .min(HERD_LIMIT);
+        let courage = behaviour.courage + behaviour.herd_safety * herd;
+        let own_power = life.combat_power() * courage / 100;
         let threat = perception
             .entities
             .iter()
-            .find(|entity| entity.species != own_species && entity.steps.len() <= THREAT_RANGE)
+            .find(|entity| {
+                entity.species != own_species && entity.steps.len() <= behaviour.threat_range
+            })
             .map(|entity| ThreatAssessment {
                 target_id: entity.id,
                 can_win: own_power >= entity.power,
@@ -66,18 +136,31 @@ impl InstinctEvaluator {
                 threat: None,
             };
         }
-        if life.is_energy_low() {
+        // Tükenmiş canlı açlıktan önce uyumak ister
+        if life.is_exhausted() {
+            return InstinctDecision {
+                instinct: Instinct::Fatigue,
+                threat: None,
+            };
+        }
+        if life.energy * 100 <= life.genome.max_energy * behaviour.hunger {
             return InstinctDecision {
                 instinct: Instinct::Hunger,
                 threat: None,
             };
         }
-        if life.is_water_low() {
+        if life.water * 100 <= life.genome.max_water * behaviour.thirst {
             return InstinctDecision {
                 instinct: Instinct::Thirst,
                 threat: None,
             };
         }
+        if life.fatigue * 100 >= life.genome.max_fatigue * behaviour.fatigue {
+            return InstinctDecision {
+                instinct: Instinct::Fatigue,
+                threat: None,
+            };
+        }
         if life.can_reproduce() && !perception.entities.is_empty() {
             return InstinctDecision {
                 instinct: Instinct::Mating,
@@ -1,4 +1,4 @@
-use crate::map::movement::Steps;
+use crate::{entity::signal::SignalKind, map::movement::Steps};
 
 /// Canlının görüş açısıyla yola çıkarak ortaya koyduğu niyet
 #[derive(Debug, Clone, PartialEq)]
@@ -17,8 +17,12 @@ pub enum Intent {
     Attack { target_id: usize },
     /// Kaçınılmak istenilen canlı
     Flee { target_id: usize },
-    /// Bekleme niyeti, iyileşme için (yavaş)
+    /// Bekleme niyeti, yerinde kalırsa yorgunluğu yavaşça azaltır
     Idle { duration: usize },
-    /// Keyfi olarak uyuma eylemi, iyileşme için (hızlı)
+    /// Çağrı gönderme niyeti, menzildeki canlılar sonraki tick duyar
+    /// Menzil arttıkça çağrı daha çok enerji harcatır.
+    Signal { kind: SignalKind, range: usize },
+    /// Uyuma eylemi, yorgunluğu ve canı hızlı toparlar
+    /// Uyuyan canlı savunmasızdır, saldırıya uğrarsa ya da tehdit yaklaşırsa uyanır
     Sleep { duration: usize },
 }
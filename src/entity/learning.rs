@@ -0,0 +1,245 @@
+use crate::{
+    entity::{
+        decision::{ACTION_COUNT, DecisionContext},
+        outcome::Outcome,
+        species::Diet,
+    },
+    gen_range,
+};
+use std::{collections::HashMap, fmt::Write as _, fs, io, path::Path};
+
+/// Her yeni yavru için ödül
+pub const OFFSPRING_REWARD: f32 = 50.0;
+
+/// Ölüm cezası
+pub const DEATH_PENALTY: f32 = 100.0;
+
+/// Görüş açısını ayrık bir duruma çevir
+///
+/// Bitler: enerji ve su çeyreği (2'şer bit), can düşük, yorgun,
+/// yenebilir yemek görünüyor, su görünüyor, tehdit yakın,
+/// tehdit yenilebilir, eş görünüyor.
+pub fn encode_state(ctx: &DecisionContext, diet: Diet) -> u32 {
+    let life = ctx.life;
+    let perception = ctx.perception;
+    let quarter = |value: usize, max: usize| (value * 4 / max.max(1)).min(3) as u32;
+
+    let food_seen = perception.foods.iter().any(|f| {
+        if f.is_corpse {
+            diet.eats_meat()
+        } else {
+            diet.eats_plants()
+        }
+    });
+    let mate_seen = perception
+        .entities
+        .iter()
+        .any(|e| e.species == ctx.species && e.sex != life.sex);
+    let threat = ctx.decision.threat;
+
+    let bits = [
+        life.is_health_low(),
+        life.is_tired(),
+        food_seen,
+        !perception.waters.is_empty(),
+        threat.is_some(),
+        threat.is_some_and(|t| t.can_win),
+        mate_seen,
+    ];
+    let mut state = quarter(life.energy, life.genome.max_energy)
+        | quarter(life.water, life.genome.max_water) << 2;
+    for (index, bit) in bits.iter().enumerate() {
+        if *bit {
+            state |= 1 << (4 + index);
+        }
+    }
+    state
+}
+
+/// Tick sonucunun ödülü
+/// Can, enerji ve su değişimleri artı yavru ödülü, eksi ölüm cezası.
+pub fn reward(outcome: &Outcome) -> f32 {
+    let mut reward = (outcome.health + outcome.energy + outcome.water) as f32;
+    reward += outcome.offspring as f32 * OFFSPRING_REWARD;
+    if outcome.died {
+        reward -= DEATH_PENALTY;
+    }
+    reward
+}
+
+/// Durum-eylem değer tablosu (tabular Q-learning)
+#[derive(Debug, Clone)]
+pub struct QTable {
+    /// Durum başına eylem değerleri
+    pub values: HashMap<u32, [f32; ACTION_COUNT]>,
+    /// Öğrenme hızı
+    pub alpha: f32,
+    /// Gelecek ödüllerin indirimi
+    pub gamma: f32,
+    /// Keşif ihtimali (yüzde), rastgele eylem seçilir
+    pub epsilon: usize,
+}
+
+impl Default for QTable {
+    fn default() -> Self {
+        Self::new(0.1, 0.9, 10)
+    }
+}
+
+impl QTable {
+    /// Boş tablo
+    pub fn new(alpha: f32, gamma: f32, epsilon: usize) -> Self {
+        Self {
+            values: HashMap::new(),
+            alpha,
+            gamma,
+            epsilon,
+        }
+    }
+
+    /// Durumun eylem değerleri, görülmemiş durumlar için sıfır
+    pub fn q(&self, state: u32) -> [f32; ACTION_COUNT] {
+        self.values
+            .get(&state)
+            .copied()
+            .unwrap_or([0.0; ACTION_COUNT])
+    }
+
+    /// En değerli eylem, eşitlikte ilk sıradaki
+    pub fn best(&self, state: u32) -> usize {
+        let q = self.q(state);
+        (0..ACTION_COUNT).fold(
+            0,
+            |best, action| {
+                if q[action] > q[best] { action } else { best }
+            },
+        )
+    }
+
+    /// Epsilon-greedy seçim
+    pub fn choose(&self, state: u32) -> usize {
+        if gen_range(1, 100) as usize <= self.epsilon {
+            return gen_range(0, ACTION_COUNT as isize - 1) as usize;
+        }
+        self.best(state)
+    }
+
+    /// Tek adım güncelleme
+    /// `next` yoksa (ölüm) gelecek değeri sıfır kabul edilir.
+    pub fn update(&mut self, state: u32, action: usize, reward: f32, next: Option<u32>) {
+        let future = next.map_or(0.0, |next| {
+            self.q(next).into_iter().fold(f32::MIN, f32::max)
+        });
+        let (alpha, gamma) = (self.alpha, self.gamma);
+        let q = self.values.entry(state).or_insert([0.0; ACTION_COUNT]);
+        q[action] += alpha * (reward + gamma * future - q[action]);
+    }
+
+    /// Tabloyu metne çevir
+    /// İlk satır parametrelerdir, sonra her satırda bir durum ve eylem değerleri.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{} {} {}\n", self.alpha, self.gamma, self.epsilon);
+        let mut states: Vec<&u32> = self.values.keys().collect();
+        states.sort();
+        for state in states {
+            let _ = write!(text, "{}", state);
+            for value in self.values[state] {
+                let _ = write!(text, " {}", value);
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// `to_text` çıktısından tabloyu oku
+    pub fn from_text(text: &str) -> io::Result<Self> {
+        let invalid = |line: usize| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("satır {}: geçersiz Q tablosu", line),
+            )
+        };
+        let mut lines = text.lines().enumerate();
+        let (_, header) = lines.next().ok_or_else(|| invalid(1))?;
+        let params: Vec<&str> = header.split_whitespace().collect();
+        let [alpha, gamma, epsilon] = params.as_slice() else {
+            return Err(invalid(1));
+        };
+        let mut table = Self::new(
+            alpha.parse().map_err(|_| invalid(1))?,
+            gamma.parse().map_err(|_| invalid(1))?,
+            epsilon.parse().map_err(|_| invalid(1))?,
+        );
+
+        for (index, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let state: u32 = parts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| invalid(index + 1))?;
+            let mut q = [0.0; ACTION_COUNT];
+            for value in q.iter_mut() {
+                *value = parts
+                    .next()
+                    .and_then(|v| v.parse().ok())
+                    .ok_or_else(|| invalid(index + 1))?;
+            }
+            table.values.insert(state, q);
+        }
+        Ok(table)
+    }
+
+    /// Tabloyu dosyaya kaydet
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    /// Tabloyu dosyadan oku
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_text(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_round_trip() {
+        let mut table = QTable::new(0.25, 0.5, 7);
+        table.update(3, 1, 4.0, None);
+        table.update(42, 0, -2.5, Some(3));
+        table.update(7, ACTION_COUNT - 1, 0.125, None);
+
+        let read = QTable::from_text(&table.to_text()).unwrap();
+        assert_eq!(read.alpha, table.alpha);
+        assert_eq!(read.gamma, table.gamma);
+        assert_eq!(read.epsilon, table.epsilon);
+        assert_eq!(read.values, table.values);
+        assert_eq!(read.to_text(), table.to_text());
+    }
+
+    #[test]
+    fn empty_table_round_trip() {
+        let read = QTable::from_text(&QTable::default().to_text()).unwrap();
+        assert!(read.values.is_empty());
+        assert_eq!(read.epsilon, 10);
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        let error_line = |text: &str| QTable::from_text(text).unwrap_err().to_string();
+
+        assert!(error_line("").contains("satır 1"));
+        assert!(error_line("0.1 0.9\n").contains("satır 1"));
+        assert!(error_line("0.1 0.9 on\n").contains("satır 1"));
+        // Eksik eylem değeri
+        assert!(error_line("0.1 0.9 10\n\n5 1.0\n").contains("satır 3"));
+        // Sayı olmayan durum
+        let row = ["0"; ACTION_COUNT].join(" ");
+        assert!(error_line(&format!("0.1 0.9 10\nx {}\n", row)).contains("satır 2"));
+    }
+}
@@ -1,30 +1,25 @@
-//use crate::entity::{intent::Intent, phase::EntityPhase};
+use crate::entity::{
+    genome::Genome,
+    intent::Intent,
+    memory::Memory,
+    phase::EntityPhase,
+    reproduction::{Gestation, Sex},
+    signal::MAX_SIGNAL_RANGE,
+};
 
 /// Bu struct hem:
-/// - genetik (sabit) bilgileri
+/// - genetik (sabit) bilgileri, `genome` içinde
 /// - dinamik (tick ile değişen) bilgileri
+///
 /// birlikte tutar.
-/// Ayrım yorumlar ve yardımcı fonksiyonlarla yapılır.
 #[derive(Debug, Clone)]
 pub struct LifeState {
     // -------- GENETİK (SABİT) --------
-    /// Maksimum yaş (tick cinsinden)
-    pub max_age: usize,
+    /// Kalıtsal özellikler, yavruya ebeveynlerden aktarılır
+    pub genome: Genome,
 
-    /// Maksimum can
-    pub max_health: usize,
-
-    /// Maksimum enerji
-    pub max_energy: usize,
-
-    /// Maksimum su
-    pub max_water: usize,
-
-    /// Üreme için minimum yaş
-    pub maturity_age: usize,
-
-    /// Canlının görüş açısı
-    pub vision_range: usize, // Örn: 6
+    /// Cinsiyet, doğumda belirlenir
+    pub sex: Sex,
 
     // -------- DİNAMİK (DEĞİŞEN) --------
     /// Şu ana kadar geçen tick sayısı
@@ -42,25 +37,92 @@ pub struct LifeState {
     /// Son çiftleşmeden sonra kalan bekleme süresi
     pub reproduction_cooldown: usize,
 
-    /// Tick başına maksimum hareket hakkı
-    pub speed: usize,
-
     /// Bu tick içinde kullanılan hareket sayısı
     pub moves_used: usize,
+
+    /// Birikmiş yorgunluk, uyuyarak azalır
+    pub fatigue: usize,
+
+    /// Dişi gebeyse, doğuma kadar taşınan gebelik
+    pub gestation: Option<Gestation>,
+
+    /// Görülen kaynak ve tehditlerin hafızası, zamanla silinir
+    pub memory: Memory,
+
+    /// Boy, yetişkin boyunun yüzdesi; yavru yedikçe büyür
+    pub size: usize,
 }
 
+/// Uykuda her tick azalan yorgunluk
+pub const SLEEP_FATIGUE_RECOVERY: usize = 3;
+
+/// Uykuda her tick kazanılan can
+pub const SLEEP_HEAL: usize = 3;
+
+/// Bazal metabolizma: sadece hayatta kalmanın tick başına enerji maliyeti
+pub const BASAL_METABOLISM: usize = 1;
+
+/// Görüşün her bu kadar birimi tick başına 1 enerji tutar
+pub const VISION_COST_DIVISOR: usize = 5;
+
+/// Hareket ederken hızın her bu kadar birimi 1 enerji tutar
+pub const SPEED_COST_DIVISOR: usize = 3;
+
+/// Kur yapmanın (çiftleşme denemesi) enerji maliyeti
+pub const COURTSHIP_COST: usize = 2;
+
+/// Çağrı menzilinin her bu kadar birimi 1 enerji tutar
+pub const SIGNAL_COST_DIVISOR: usize = 4;
+
+/// Yaşlılıkta yitirilen gücün her bu kadar yüzdesi tick başına 1 can götürür
+pub const FRAILTY_DIVISOR: usize = 20;
+
+/// Bu gücün (yüzde) altındaki yaşlılar artık üreyemez
+pub const FERTILITY_MIN_VITALITY: usize = 40;
+
+/// Büyüyen canlıda her bu kadar birim yemek boyu %1 uzatır
+pub const GROWTH_DIVISOR: usize = 2;
+
 impl LifeState {
+    /// Genomdan tam dolu, yetişkin (ergenlik yaşında) bir yaşam durumu oluştur
+    pub fn new(genome: Genome) -> Self {
+        Self {
+            age: genome.maturity_age,
+            health: genome.max_health,
+            energy: genome.max_energy,
+            water: genome.max_water,
+            reproduction_cooldown: 0,
+            moves_used: 0,
+            fatigue: 0,
+            gestation: None,
+            memory: Memory::default(),
+            size: 100,
+            sex: Sex::random(),
+            genome,
+        }
+    }
+
+    /// Yavru için yaşam durumu, küçük doğar ve can/enerji/su yarı dolu başlar
+    pub fn newborn(genome: Genome) -> Self {
+        let mut life = Self::new(genome);
+        life.age = 0;
+        life.size = life.genome.ageing.juvenile.clamp(1, 100);
+        life.health = life.max_health() / 2;
+        life.energy = life.genome.max_energy / 2;
+        life.water = life.genome.max_water / 2;
+        life
+    }
+
     /// Her tick başında çağrılır.
     /// Hareket hakkı resetlenir.
     pub fn tick(&mut self) {
         // Yaşlanma
         self.age += 1;
 
-        // Yaşlılıktan ölüm
-        if self.age > self.max_age {
-            self.health = 0;
-            // Kendine not: Yaşlılıktan ölmek yerine her turda 5 can alacak şekilde değiştirilebilir.
-            return; // Yaşlandığı için ekstra bir hesaplamaya gerek yok
+        // Yaşlılık: güç azaldıkça can kaybı artar, en yüksek can da düşer
+        self.age_body();
+        if !self.is_alive() {
+            return; // Yaşlılıktan öldü, ekstra bir hesaplamaya gerek yok
         }
 
         // Üreme bekleme süresi
@@ -68,9 +130,17 @@ impl LifeState {
             self.reproduction_cooldown -= 1;
         }
 
+        // Gebelik ilerler
+        if let Some(gestation) = &mut self.gestation {
+            gestation.tick();
+        }
+
+        // Hatıralar eskir
+        self.memory.decay();
+
         // Pasif iyileşme süreci
         // 2 enerji'ye 1 can düşer; değerler değişebilir şimdilik bu
-        if !self.is_energy_low() && self.health < self.max_health {
+        if !self.is_energy_low() && self.health < self.max_health() {
             self.consume_energy(2);
             self.heal(1);
         }
@@ -78,7 +148,7 @@ impl LifeState {
         // Can karşılığında Enerji kazanma
         // Enerji 0 ise, Can yakarak Enerji kazanma
         if self.energy == 0 && !self.is_health_low() {
-            self.health -= 3;
+            self.health = self.health.saturating_sub(3);
             self.restore_energy(9);
         }
 
@@ -87,27 +157,132 @@ impl LifeState {
             self.health = self.health.saturating_sub(2);
         }
 
-        self.consume_energy(1);
+        // Tükenmiş canlı uykusuzluktan can kaybeder
+        if self.is_exhausted() {
+            self.take_damage(1);
+        }
+
+        // Enerji harcaması World tarafından `metabolic_cost` ile düşülür
         self.consume_water(1);
+        self.fatigue = (self.fatigue + 1).min(self.genome.max_fatigue);
 
         // Bu tick için hareket sayacı sıfırlanır
         self.moves_used = 0;
     }
 
+    /// Uykudayken her tick çağrılır.
+    /// Metabolizma yavaşlar (su iki tickte bir harcanır, enerji için `metabolic_cost`),
+    /// iyileşme hızlanır ve yorgunluk azalır.
+    pub fn sleep_tick(&mut self) {
+        self.age += 1;
+        self.age_body();
+        if !self.is_alive() {
+            return;
+        }
+
+        if self.reproduction_cooldown > 0 {
+            self.reproduction_cooldown -= 1;
+        }
+        if let Some(gestation) = &mut self.gestation {
+            gestation.tick();
+        }
+        self.memory.decay();
+
+        // Susuz ve aç uyku iyileştirmez
+        if self.energy > 0 && self.water > 0 {
+            self.heal(SLEEP_HEAL);
+        }
+        if self.water == 0 && !self.is_health_low() {
+            self.health = self.health.saturating_sub(2);
+        }
+
+        if self.age.is_multiple_of(2) {
+            self.consume_water(1);
+        }
+        self.fatigue = self.fatigue.saturating_sub(SLEEP_FATIGUE_RECOVERY);
+
+        self.moves_used = 0;
+    }
+
+    /// Yaşın bedene etkisi, ölüm bir uçurumdan değil birikmiş zayıflıktan gelir
+    fn age_body(&mut self) {
+        self.take_damage(self.frailty());
+        self.health = self.health.min(self.max_health());
+    }
+
     // ===============================
     // DURUM SORGULARI
     // ===============================
+    /// Yaşa göre güç (yüzde), bkz. `Ageing`
+    pub fn vitality(&self) -> usize {
+        self.genome
+            .ageing
+            .vitality(self.age, self.genome.maturity_age, self.genome.max_age)
+    }
+
+    /// Yaşlılığın tick başına aldığı can
+    pub fn frailty(&self) -> usize {
+        if !self.is_mature() {
+            return 0;
+        }
+        (100 - self.vitality()) / FRAILTY_DIVISOR
+    }
+
+    /// Boy ve yaşa göre en yüksek can
+    pub fn max_health(&self) -> usize {
+        self.genome.max_health * self.size / 100 * self.vitality() / 100
+    }
+
+    /// Boy ve yaşa göre tick başına hareket hakkı (en az 1)
+    pub fn speed(&self) -> usize {
+        (self.genome.speed * self.size * self.vitality() / 10_000).max(1)
+    }
+
+    /// Boya göre saldırı gücü (en az 1)
+    pub fn attack(&self) -> usize {
+        (self.genome.attack * self.size / 100).max(1)
+    }
+
+    /// Yaşa göre görüş mesafesi (en az 1)
+    pub fn vision_range(&self) -> usize {
+        (self.genome.vision_range * self.vitality() / 100).max(1)
+    }
+
+    /// Yaşa göre bir doğumdaki en fazla yavru (en az 1)
+    pub fn litter_size(&self) -> usize {
+        (self.genome.litter_size * self.vitality())
+            .div_ceil(100)
+            .max(1)
+    }
+
+    /// Yaşlılık doğurganlığı bitirdi mi?
+    pub fn is_fertile(&self) -> bool {
+        self.vitality() >= FERTILITY_MIN_VITALITY
+    }
+
     /// Enerji düşük kabul edilen eşik
     pub fn low_energy_threshold(&self) -> usize {
-        self.max_energy / 4
+        self.genome.max_energy / 4
     }
     /// Can düşük kabul edilen eşik
     pub fn low_health_threshold(&self) -> usize {
-        self.max_health / 4
+        self.max_health() / 4
     }
     /// Su düşük kabul edilen eşik
     pub fn low_water_threshold(&self) -> usize {
-        self.max_water / 4
+        self.genome.max_water / 4
+    }
+
+    /// Dövüş gücü tahmini
+    /// Hem algıda hem tehdit değerlendirmesinde aynı ölçü kullanılır.
+    pub fn combat_power(&self) -> usize {
+        (self.health + self.energy) * (self.attack() + self.genome.defense + self.genome.agility)
+            / 10
+    }
+
+    /// Vücut kütlesi, öldüğünde bırakacağı leşin miktarı
+    pub fn body_mass(&self) -> usize {
+        self.genome.max_health * self.size / 200
     }
 
     pub fn is_alive(&self) -> bool {
@@ -115,7 +290,12 @@ impl LifeState {
     }
 
     pub fn is_mature(&self) -> bool {
-        self.age >= self.maturity_age
+        self.age >= self.genome.maturity_age
+    }
+
+    /// Ebeveynlerine bağımlı yavru mu? (ergenliğe kadar)
+    pub fn is_juvenile(&self) -> bool {
+        !self.is_mature()
     }
 
     pub fn is_energy_low(&self) -> bool {
@@ -123,7 +303,7 @@ impl LifeState {
     }
 
     pub fn is_energy_full(&self) -> bool {
-        self.energy >= self.max_energy
+        self.energy >= self.genome.max_energy
     }
 
     pub fn is_health_low(&self) -> bool {
@@ -131,7 +311,7 @@ impl LifeState {
     }
 
     pub fn is_health_full(&self) -> bool {
-        self.health >= self.max_health
+        self.health >= self.max_health()
     }
 
     pub fn is_water_low(&self) -> bool {
@@ -139,18 +319,47 @@ impl LifeState {
     }
 
     pub fn is_water_full(&self) -> bool {
-        self.water >= self.max_water
+        self.water >= self.genome.max_water
+    }
+
+    /// Uyku ihtiyacı başladı mı?
+    pub fn is_tired(&self) -> bool {
+        self.fatigue >= self.genome.max_fatigue * 3 / 4
+    }
+
+    /// Yorgunluk sınıra dayandı mı?
+    pub fn is_exhausted(&self) -> bool {
+        self.fatigue >= self.genome.max_fatigue
+    }
+
+    /// Yorgunluğun tamamen geçmesi için gereken uyku süresi (tick)
+    pub fn sleep_duration(&self) -> usize {
+        self.fatigue.div_ceil(SLEEP_FATIGUE_RECOVERY).max(1)
     }
 
     // LifeState içinde
     pub fn can_reproduce(&self) -> bool {
-        (self.age >= self.maturity_age) && (self.reproduction_cooldown == 0 && self.energy > 15)
+        (self.age >= self.genome.maturity_age)
+            && (self.reproduction_cooldown == 0 && self.energy > 15)
+            && !self.is_pregnant()
+            && self.is_fertile()
         // Çok düşük tut ki ölmeden hemen önce bile deneyebilsinler
     }
 
+    /// Gebe mi?
+    pub fn is_pregnant(&self) -> bool {
+        self.gestation.is_some()
+    }
+
+    /// Kur yapan canlıyı eş olarak kabul eder mi?
+    /// Karşı cinsiyetten olmalı ve iki taraf da üremeye hazır olmalı.
+    pub fn accepts_mate(&self, suitor: &LifeState) -> bool {
+        self.can_reproduce() && suitor.can_reproduce() && self.sex != suitor.sex
+    }
+
     /// Bu tick içinde hareket edebilir mi?
     pub fn can_move(&self) -> bool {
-        self.moves_used < self.speed
+        self.moves_used < self.speed()
     }
 
     pub fn can_move_for(&self, need: usize) -> bool {
@@ -164,7 +373,7 @@ impl LifeState {
 
     /// Yeterli hareket hakkı var mı?
     pub fn enough_moves(&self, need: usize) -> bool {
-        self.moves_used.saturating_add(need) <= self.speed
+        self.moves_used.saturating_add(need) <= self.speed()
     }
     // ===============================
     // DURUM DEĞİŞTİRİCİLER
@@ -182,7 +391,15 @@ impl LifeState {
 
     pub fn restore_energy(&mut self, amount: usize) {
         // Enerjiyi artır ama maksimum kapasiteyi aşma
-        self.energy = (self.energy + amount).min(self.max_energy);
+        self.energy = (self.energy + amount).min(self.genome.max_energy);
+    }
+
+    /// Yemek (bitki, leş ya da süt) ile enerji kazan, büyüyen canlı boy da atar
+    pub fn eat(&mut self, amount: usize) {
+        self.restore_energy(amount);
+        if self.size < 100 {
+            self.size = (self.size + amount / GROWTH_DIVISOR).min(100);
+        }
     }
 
     pub fn consume_water(&mut self, amount: usize) {
@@ -190,11 +407,16 @@ impl LifeState {
     }
 
     pub fn restore_water(&mut self, amount: usize) {
-        self.water = (self.water + amount).min(self.max_water);
+        self.water = (self.water + amount).min(self.genome.max_water);
+    }
+
+    /// Bekleyerek dinlenme, yorgunluğu yavaşça azaltır
+    pub fn rest(&mut self) {
+        self.fatigue = self.fatigue.saturating_sub(2);
     }
 
     pub fn heal(&mut self, amount: usize) {
-        self.health = (self.health + amount).min(self.max_health);
+        self.health = (self.health + amount).min(self.max_health());
     }
 
     /// Hasar al
@@ -202,51 +424,161 @@ impl LifeState {
         self.health = self.health.saturating_sub(amount);
     }
 
+    /// Dişiyi döllenmiş duruma getirir
+    pub fn conceive(&mut self, father_id: usize, father: Genome) {
+        self.gestation = Some(Gestation::new(
+            self.genome.gestation_period,
+            father_id,
+            father,
+        ));
+    }
+
+    /// Doğum zamanı gelmişse gebeliği sonlandırıp döndürür
+    pub fn take_due_gestation(&mut self) -> Option<Gestation> {
+        if self.gestation.as_ref().is_some_and(|g| g.is_due()) {
+            return self.gestation.take();
+        }
+        None
+    }
+
+    /// Doğan her yavrunun anneye enerji maliyeti
+    pub fn birth_cost(&self) -> usize {
+        self.genome.max_energy / 8
+    }
+
     pub fn on_reproduce(&mut self) {
-        println!("Entity is reproducing...");
-        self.reproduction_cooldown = 100;
+        self.reproduction_cooldown = self.genome.reproduction_cooldown;
         self.consume_energy(10);
     }
 
-    /*
+    /// Bu tick içinde harcanan enerji, faz ve niyete göre
+    /// Adımların enerjisi `on_move` ile ayrıca düşer.
+    /// Geniş görüş ve hızlı bacaklar enerji tüketir, böylece genler arasında bir bedel oluşur.
     pub fn metabolic_cost(&self, phase: &EntityPhase, intent: Option<&Intent>) -> usize {
-        // 1. Bazal Metabolizma Hızı (BMR): Sadece hayatta kalmak için gereken min. enerji
-        let bmr = 1;
-
         match phase {
             // Ölüler enerji harcamaz
             EntityPhase::Corpse { .. } | EntityPhase::Removed => 0,
 
-            // Uyku Modu: En düşük maliyet. Görüş kapalı, hareket yok.
-            EntityPhase::Sleeping { .. } => bmr,
+            // Uyku: metabolizma yarıya iner, görüş kapalı, hareket yok
+            EntityPhase::Sleeping { .. } => {
+                if self.age.is_multiple_of(2) {
+                    BASAL_METABOLISM
+                } else {
+                    0
+                }
+            }
 
-            // Aktif Mod: Canlı uyanık ve çevresini işliyor.
+            // Uyanık: bazal metabolizma + algı + niyetin maliyeti
             EntityPhase::Active => {
-                let mut cost = bmr;
-
-                // Algı Maliyeti: Geniş bir alanı taramak (vision_range) beyin/göz yorar.
-                cost += self.vision_range / 5; // Örn: Her 5 birim görüş +1 maliyet
-
-                // Niyet (Aksiyon) Maliyeti:
-                if let Some(action) = intent {
-                    match *action {
-                        Intent::Move { steps } | Intent::Flee { target_id: steps } => {
-                            // Hareket maliyeti: Hız ve atılan adım sayısı ile orantılı
-                            cost += self.speed + (steps.len() / 2);
-                        }
-                        Intent::Mate { .. } => {
-                            cost += 5; // Üreme çok yüksek enerji gerektirir
-                        }
-                        Intent::Eat { .. } => {
-                            cost += 1; // Sindirim ve çiğneme eforu
-                        }
-                        Intent::Idle { .. } => {
-                            // Idle (Bekleme): Ekstra maliyet yok, sadece BMR + Algı.
-                        }
+                let mut cost = BASAL_METABOLISM + self.vision_range() / VISION_COST_DIVISOR;
+                match intent {
+                    // Hızlı bacakları çalıştırmak pahalıdır
+                    Some(Intent::Move { .. } | Intent::Flee { .. }) => {
+                        cost += self.speed() / SPEED_COST_DIVISOR;
+                    }
+                    Some(Intent::Mate { .. }) => cost += COURTSHIP_COST,
+                    // Uzağa ulaşan çağrı daha yorucudur
+                    Some(Intent::Signal { range, .. }) => {
+                        cost += range.min(&MAX_SIGNAL_RANGE).div_ceil(SIGNAL_COST_DIVISOR);
                     }
+                    // Sindirim ve çiğneme eforu
+                    Some(Intent::Eat { .. }) => cost += 1,
+                    // Saldırının bedeli dövüşte ödenir, içmek, beklemek ve uyumak ek maliyetsizdir
+                    Some(
+                        Intent::Attack { .. }
+                        | Intent::Drink { .. }
+                        | Intent::Idle { .. }
+                        | Intent::Sleep { .. },
+                    )
+                    | None => {}
                 }
                 cost
             }
         }
-    }*/
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        creatures::config::default_species, entity::signal::SignalKind, map::movement::Steps,
+    };
+
+    #[test]
+    fn old_starving_creature_does_not_underflow() {
+        // Yaşlandıkça max_health küçülür, düşük can eşiği 0-1'e iner
+        let mut life = LifeState::new(default_species("herbivore").info.genome.clone());
+        life.age = 143;
+        life.health = life.max_health();
+        life.energy = 0;
+        for _ in 0..5 {
+            let before = life.health;
+            life.tick();
+            assert!(life.health <= before);
+            life.energy = 0;
+        }
+        assert_eq!(life.health, 0);
+    }
+
+    #[test]
+    fn reproduction_cooldown_comes_from_genome() {
+        let mut genome = default_species("herbivore").info.genome.clone();
+        genome.reproduction_cooldown = 7;
+        let mut life = LifeState::new(genome);
+        life.on_reproduce();
+        assert_eq!(life.reproduction_cooldown, 7);
+    }
+
+    #[test]
+    fn metabolic_cost_by_phase_and_intent() {
+        let mut genome = default_species("herbivore").info.genome.clone();
+        genome.vision_range = 10;
+        genome.speed = 6;
+        let life = LifeState::new(genome);
+        let active = EntityPhase::Active;
+        let cost = |intent: Intent| life.metabolic_cost(&active, Some(&intent));
+
+        let sleeping = life.metabolic_cost(&EntityPhase::Sleeping { remaining: 3 }, None);
+        let idle = cost(Intent::Idle { duration: 1 });
+        let moving = cost(Intent::Move {
+            steps: Steps::empty(),
+        });
+        assert!(sleeping < idle);
+        assert!(idle < moving);
+        assert_eq!(moving, cost(Intent::Flee { target_id: 1 }));
+        assert_eq!(idle, life.metabolic_cost(&active, None));
+        assert!(idle < cost(Intent::Mate { target_id: 1 }));
+        let corpse = EntityPhase::Corpse {
+            remaining: 3,
+            meat: 3,
+        };
+        assert_eq!(life.metabolic_cost(&corpse, None), 0);
+    }
+
+    #[test]
+    fn wider_senses_cost_more() {
+        let genome = default_species("herbivore").info.genome.clone();
+        let mut wide = genome.clone();
+        wide.vision_range = genome.vision_range + VISION_COST_DIVISOR * 2;
+        let idle = Intent::Idle { duration: 1 };
+        assert!(
+            LifeState::new(genome).metabolic_cost(&EntityPhase::Active, Some(&idle))
+                < LifeState::new(wide).metabolic_cost(&EntityPhase::Active, Some(&idle))
+        );
+
+        let life = LifeState::new(default_species("herbivore").info.genome.clone());
+        let call = |range| {
+            life.metabolic_cost(
+                &EntityPhase::Active,
+                Some(&Intent::Signal {
+                    kind: SignalKind::Alarm,
+                    range,
+                }),
+            )
+        };
+        assert!(call(2) < call(MAX_SIGNAL_RANGE));
+        // Menzil `MAX_SIGNAL_RANGE` ile sınırlı, daha uzağı daha pahalı değil
+        assert_eq!(call(MAX_SIGNAL_RANGE), call(MAX_SIGNAL_RANGE * 4));
+    }
 }
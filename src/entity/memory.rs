@@ -0,0 +1,260 @@
+use crate::{
+    entity::{perception::Perception, species::Species},
+    map::movement::{Position, Steps},
+};
+use std::collections::HashMap;
+
+/// Bir hatıranın unutulmaya kadar dayandığı tick
+pub const MEMORY_DURATION: usize = 60;
+
+/// Hafızada aynı anda tutulabilecek en fazla yer
+pub const MEMORY_CAPACITY: usize = 32;
+
+/// Bir hücrenin tamamen "yeni" sayılması için geçmesi gereken tick
+/// Bu süreden eski ziyaretler unutulur.
+pub const NOVELTY_HORIZON: usize = 100;
+
+/// Hatırlanan yerin türü
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    /// Bitki (harita yemeği)
+    Plant,
+    /// Leş
+    Carcass,
+    /// Su kaynağı
+    Water,
+    /// Onu avlayabilecek başka türden bir canlı
+    Threat(Species),
+}
+
+/// Hafızadaki tek bir yer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recollection {
+    pub kind: MemoryKind,
+    /// Dünya koordinatı
+    pub pos: Position,
+    /// Kalan tazelik, her tick azalır ve 0 olunca unutulur
+    pub strength: usize,
+}
+
+/// Canlının tickler arası hafızası
+///
+/// Görülen kaynaklar ve tehditler dünya koordinatı ile saklanır,
+/// zamanla silinir. Görüş alanında olduğu halde artık görünmeyen
+/// yerler hemen unutulur (yenmiş yemek, gitmiş tehdit).
+/// Reddeden eşler de bir süre hatırlanır.
+/// Ziyaret edilen hücreler merak (keşif) için ayrıca tutulur.
+#[derive(Debug, Clone)]
+pub struct Memory {
+    /// Hatırlanan yerler
+    pub places: Vec<Recollection>,
+    /// Çiftleşmeyi reddeden canlılar (kimlik, kalan tazelik)
+    pub refusals: Vec<(usize, usize)>,
+    /// Yeni bir hatıranın tazeliği
+    pub duration: usize,
+    /// Hücrelerin son ziyaret zamanı (`clock` cinsinden)
+    pub visits: HashMap<Position, usize>,
+    /// Hafızanın kendi saati, her `decay` ile ilerler
+    pub clock: usize,
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new(MEMORY_DURATION)
+    }
+}
+
+impl Memory {
+    /// Hatıraları `duration` tick dayanan boş hafıza
+    pub fn new(duration: usize) -> Self {
+        Self {
+            places: Vec::new(),
+            refusals: Vec::new(),
+            duration,
+            visits: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Görüş açısını hafızaya işle
+    /// `radius` içinde olup artık görünmeyen yerler unutulur.
+    pub fn observe(&mut self, perception: &Perception, own_species: Species, radius: usize) {
+        let origin = perception.origin;
+        self.visits.insert(origin, self.clock);
+        self.places
+            .retain(|place| origin.distance_to(place.pos) > radius);
+
+        for food in &perception.foods {
+            let kind = if food.is_corpse {
+                MemoryKind::Carcass
+            } else {
+                MemoryKind::Plant
+            };
+            self.remember(kind, target_of(origin, &food.steps));
+        }
+        for water in &perception.waters {
+            self.remember(MemoryKind::Water, target_of(origin, &water.steps));
+        }
+        for other in &perception.entities {
+            // Sadece avlanabileceği türler tehdittir, avı hatırlanmaz
+            if other.species != own_species && other.is_predator() {
+                self.remember(
+                    MemoryKind::Threat(other.species),
+                    target_of(origin, &other.steps),
+                );
+            }
+        }
+
+        // Kapasite aşılırsa en eski hatıralar gider
+        if self.places.len() > MEMORY_CAPACITY {
+            self.places.sort_by_key(|place| usize::MAX - place.strength);
+            self.places.truncate(MEMORY_CAPACITY);
+        }
+    }
+
+    /// Yeri hatırla, zaten biliniyorsa tazele
+    pub fn remember(&mut self, kind: MemoryKind, pos: Position) {
+        let strength = self.duration;
+        match self
+            .places
+            .iter_mut()
+            .find(|place| place.kind == kind && place.pos == pos)
+        {
+            Some(place) => place.strength = strength,
+            None => self.places.push(Recollection {
+                kind,
+                pos,
+                strength,
+            }),
+        }
+    }
+
+    /// Çiftleşmeyi reddeden canlıyı hatırla
+    pub fn remember_refusal(&mut self, id: usize) {
+        let strength = self.duration;
+        match self.refusals.iter_mut().find(|(refused, _)| *refused == id) {
+            Some((_, left)) => *left = strength,
+            None => self.refusals.push((id, strength)),
+        }
+    }
+
+    /// Bu canlı yakın zamanda reddetti mi?
+    pub fn has_refused(&self, id: usize) -> bool {
+        self.refusals.iter().any(|(refused, _)| *refused == id)
+    }
+
+    /// Tüm hatıraları bir tick eskit, tükenenleri unut
+    pub fn decay(&mut self) {
+        for place in &mut self.places {
+            place.strength = place.strength.saturating_sub(1);
+        }
+        self.places.retain(|place| place.strength > 0);
+        for (_, left) in &mut self.refusals {
+            *left = left.saturating_sub(1);
+        }
+        self.refusals.retain(|(_, left)| *left > 0);
+
+        self.clock += 1;
+        let clock = self.clock;
+        self.visits
+            .retain(|_, visited| clock - *visited < NOVELTY_HORIZON);
+    }
+
+    /// Hücre ne kadar yeni? Hiç ziyaret edilmediyse `NOVELTY_HORIZON`,
+    /// az önce ziyaret edildiyse 0.
+    pub fn novelty(&self, pos: Position) -> usize {
+        self.visits
+            .get(&pos)
+            .map_or(NOVELTY_HORIZON, |visited| self.clock - visited)
+            .min(NOVELTY_HORIZON)
+    }
+
+    /// `from` konumuna en yakın, koşula uyan hatıra
+    pub fn nearest(
+        &self,
+        from: Position,
+        filter: impl Fn(MemoryKind) -> bool,
+    ) -> Option<&Recollection> {
+        self.places
+            .iter()
+            .filter(|place| filter(place.kind))
+            .min_by_key(|place| (from.distance_to(place.pos), usize::MAX - place.strength))
+    }
+}
+
+/// Başlangıçtan adımlar izlenince varılan konum
+fn target_of(origin: Position, steps: &Steps) -> Position {
+    steps.iter().fold(origin, |pos, dir| pos + *dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::{
+            perception::{PerceivedEntity, Relation},
+            reproduction::Sex,
+            species::Diet,
+        },
+        map::movement::Direction,
+    };
+
+    /// İki adım doğudaki, verilen türden bir canlıyı gören görüş açısı
+    fn sees(species: Species, diet: Diet) -> Perception {
+        let mut perception = Perception::empty();
+        perception.entities.push(PerceivedEntity {
+            id: 1,
+            species,
+            diet,
+            power: 10,
+            sex: Sex::Female,
+            relation: Relation::Stranger,
+            heading: None,
+            target: None,
+            steps: Steps::new(vec![Direction::Right, Direction::Right]),
+        });
+        perception
+    }
+
+    fn threats(memory: &Memory) -> usize {
+        memory
+            .places
+            .iter()
+            .filter(|place| matches!(place.kind, MemoryKind::Threat(_)))
+            .count()
+    }
+
+    #[test]
+    fn remembers_predator_as_threat() {
+        let mut memory = Memory::default();
+        memory.observe(
+            &sees(Species::CARNIVORE, Diet::Carnivore),
+            Species::HERBIVORE,
+            0,
+        );
+        assert_eq!(threats(&memory), 1);
+        assert_eq!(memory.places[0].pos, Position::new(2, 0));
+    }
+
+    #[test]
+    fn does_not_fear_prey() {
+        let mut memory = Memory::default();
+        memory.observe(
+            &sees(Species::HERBIVORE, Diet::Herbivore),
+            Species::CARNIVORE,
+            0,
+        );
+        assert_eq!(threats(&memory), 0);
+    }
+
+    #[test]
+    fn does_not_fear_own_species() {
+        let mut memory = Memory::default();
+        memory.observe(
+            &sees(Species::CARNIVORE, Diet::Carnivore),
+            Species::CARNIVORE,
+            0,
+        );
+        assert_eq!(threats(&memory), 0);
+    }
+}
@@ -1,11 +1,24 @@
-pub mod intent;
+pub mod ageing;
+pub mod decision;
+pub mod genome;
 pub mod instinct;
+pub mod intent;
+pub mod learning;
 pub mod lifestate;
+pub mod memory;
+pub mod network;
+pub mod outcome;
 pub mod perception;
 pub mod phase;
+pub mod protocol;
+pub mod reproduction;
+pub mod signal;
 pub mod species;
 
-use crate::entity::{intent::Intent, lifestate::LifeState, perception::*, species::Species};
+use crate::entity::{
+    genome::Genome, instinct::Behaviour, intent::Intent, lifestate::LifeState, outcome::Outcome,
+    perception::*, species::Species,
+};
 
 /// Canlının temel alacağı arayüz
 pub trait Entity {
@@ -16,14 +29,47 @@ pub trait Entity {
     /// Varlık türü
     fn species(&self) -> Species;
 
+    /// Türün davranış ağırlıkları
+    /// World, uyuyanları uyandırmak gibi kararlarda bunları kullanır.
+    fn behaviour(&self) -> Behaviour {
+        Behaviour::default()
+    }
+
     /// Karar verme (sadece okuma yapmalı)
     fn make_intent(&self, view: Perception) -> Intent;
 
+    /// Karardan hemen önce, aynı görüş açısı ile çağrılır
+    /// Tickler arası durum (hafıza vb.) burada güncellenir.
+    /// Varsayılan olarak görülen kaynak ve tehditler hafızaya yazılır.
+    fn observe(&mut self, view: &Perception) {
+        let species = self.species();
+        let life = self.life_mut();
+        let radius = life.vision_range();
+        life.memory.observe(view, species, radius);
+    }
+
+    /// Tick sonunda, niyetler uygulandıktan sonra World tarafından çağrılır
+    /// Öğrenen canlılar ödülü buradan hesaplar. Varsayılan olarak bir şey yapmaz.
+    fn outcome(&mut self, _outcome: &Outcome) {}
+
     /// Tek tick güncellemesi
     /// World'un işini kolaylaştırmak için var;
     fn tick(&mut self);
 
+    /// Uykudayken tick güncellemesi
+    /// Varsayılan olarak yavaş metabolizma ve hızlı iyileşme uygular.
+    fn sleep_tick(&mut self) {
+        self.life_mut().sleep_tick();
+    }
+
+    /// Kur yapan canlıyı eş olarak kabul ediyor mu?
+    /// Çiftleşmeye son kararı hedef verir, World sadece bunu sorar.
+    fn accept_mate(&self, suitor: &LifeState) -> bool {
+        self.life().accepts_mate(suitor)
+    }
+
     /// Canlının kendi türünden yeni bir üye (yavru) oluşturmasını sağlar.
-    /// World bu metodu çağırır ama dönen somut türü (Herbivore vs.) bilmez.
-    fn reproduce(&self) -> Box<dyn Entity>;
+    /// World bu metodu doğum anında anne üzerinde çağırır ama dönen somut türü (Herbivore vs.) bilmez.
+    /// `partner` babanın genomudur, yavru ikisinden kalıtım alır.
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity>;
 }
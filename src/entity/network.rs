@@ -0,0 +1,125 @@
+use crate::{
+    entity::{
+        decision::{ACTION_COUNT, DecisionContext},
+        species::Diet,
+    },
+    gen_range,
+};
+
+/// Ağın girdi sayısı (sabit girdi hariç)
+pub const INPUT_COUNT: usize = 10;
+
+/// Gizli katmandaki nöron sayısı
+pub const HIDDEN_COUNT: usize = 8;
+
+/// Ağın toplam ağırlık sayısı (sabit girdiler dahil)
+pub const WEIGHT_COUNT: usize =
+    (INPUT_COUNT + 1) * HIDDEN_COUNT + (HIDDEN_COUNT + 1) * ACTION_COUNT;
+
+/// Sabit nokta ölçeği, 1.0 = 1000
+/// Hesaplar tam sayı ile yapılır, aynı seed her makinede aynı kararı verir.
+pub const SCALE: i64 = 1000;
+
+/// İlk nesil için rastgele ağırlıklar, [-1.0, 1.0] aralığında
+pub fn random_weights() -> Vec<i32> {
+    (0..WEIGHT_COUNT)
+        .map(|_| gen_range(-SCALE as isize, SCALE as isize) as i32)
+        .collect()
+}
+
+/// Görüş açısı ve yaşam durumundan girdileri üret (0..=SCALE)
+///
+/// Sırası: enerji, su, can, yorgunluk oranları; en yakın yenebilir yemek,
+/// su, tehdit ve eşin yakınlığı (görünmüyorsa 0); tehdidin yenilebilirliği;
+/// gebelik.
+pub fn encode_inputs(ctx: &DecisionContext, diet: Diet) -> [i64; INPUT_COUNT] {
+    let life = ctx.life;
+    let perception = ctx.perception;
+    let genome = &life.genome;
+    let ratio = |value: usize, max: usize| (value as i64 * SCALE / max.max(1) as i64).min(SCALE);
+    // Yakınlık: dibindeyse SCALE, görüşün sınırında 0'a yakın
+    let range = life.vision_range() as i64 + 1;
+    let closeness = |steps: Option<usize>| {
+        steps.map_or(0, |steps| (range - steps as i64).max(0) * SCALE / range)
+    };
+
+    let food = perception
+        .foods
+        .iter()
+        .filter(|f| {
+            if f.is_corpse {
+                diet.eats_meat()
+            } else {
+                diet.eats_plants()
+            }
+        })
+        .map(|f| f.steps.len())
+        .min();
+    let water = perception.waters.iter().map(|w| w.steps.len()).min();
+    let threat = perception
+        .entities
+        .iter()
+        .filter(|e| e.species != ctx.species)
+        .map(|e| e.steps.len())
+        .min();
+    let mate = perception
+        .entities
+        .iter()
+        .filter(|e| e.species == ctx.species && e.sex != life.sex)
+        .map(|e| e.steps.len())
+        .min();
+    let flag = |value: bool| if value { SCALE } else { 0 };
+
+    [
+        ratio(life.energy, genome.max_energy),
+        ratio(life.water, genome.max_water),
+        ratio(life.health, life.max_health()),
+        ratio(life.fatigue, genome.max_fatigue),
+        closeness(food),
+        closeness(water),
+        closeness(threat),
+        closeness(mate),
+        flag(ctx.decision.threat.is_some_and(|t| t.can_win)),
+        flag(life.is_pregnant()),
+    ]
+}
+
+/// İleri beslemeli ağı çalıştır ve en yüksek çıktının eylemini döndür
+/// Gizli katman ReLU kullanır. Ağırlık sayısı uymuyorsa `None` döner.
+pub fn decide(weights: &[i32], inputs: &[i64; INPUT_COUNT]) -> Option<usize> {
+    if weights.len() != WEIGHT_COUNT {
+        return None;
+    }
+    let (hidden_weights, output_weights) = weights.split_at((INPUT_COUNT + 1) * HIDDEN_COUNT);
+
+    let mut hidden = [0i64; HIDDEN_COUNT];
+    for (neuron, row) in hidden
+        .iter_mut()
+        .zip(hidden_weights.chunks_exact(INPUT_COUNT + 1))
+    {
+        // Son ağırlık sabit girdiye (bias) aittir
+        let sum: i64 = inputs
+            .iter()
+            .zip(row)
+            .map(|(input, weight)| input * *weight as i64)
+            .sum::<i64>()
+            / SCALE
+            + row[INPUT_COUNT] as i64;
+        *neuron = sum.max(0);
+    }
+
+    let mut best: Option<(usize, i64)> = None;
+    for (action, row) in output_weights.chunks_exact(HIDDEN_COUNT + 1).enumerate() {
+        let value = hidden
+            .iter()
+            .zip(row)
+            .map(|(neuron, weight)| neuron * *weight as i64)
+            .sum::<i64>()
+            / SCALE
+            + row[HIDDEN_COUNT] as i64;
+        if best.is_none_or(|(_, top)| value > top) {
+            best = Some((action, value));
+        }
+    }
+    best.map(|(action, _)| action)
+}
@@ -0,0 +1,19 @@
+/// Bir tick sonunda World'un canlıya bildirdiği sonuç
+/// Değişimler tick başındaki değerlere göredir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Outcome {
+    /// Sonucun ait olduğu tick
+    pub tick: usize,
+    /// Can değişimi
+    pub health: isize,
+    /// Enerji değişimi
+    pub energy: isize,
+    /// Su değişimi
+    pub water: isize,
+    /// Bu tick dünyaya gelen yavru sayısı (anne ve baba için)
+    pub offspring: usize,
+    /// Bu tick öldü mü?
+    pub died: bool,
+    /// Bu tick avlanmasına katıldığı ve ölen canlı sayısı (sürü avında herkese yazılır)
+    pub kills: usize,
+}
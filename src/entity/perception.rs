@@ -1,12 +1,28 @@
 use crate::{
-    entity::species::Species,
-    map::movement::{Direction, Steps},
+    entity::{
+        reproduction::Sex,
+        signal::SignalKind,
+        species::{Diet, Species},
+    },
+    map::movement::{Direction, Position, Steps},
 };
 use std::{
     collections::HashMap,
     ops::{Add, AddAssign},
 };
 
+/// Algılanan canlının algılayan ile akrabalığı
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Relation {
+    /// Akraba değil (ya da bilinmiyor)
+    #[default]
+    Stranger,
+    /// Algılayanın annesi ya da babası
+    Parent,
+    /// Algılayanın yavrusu
+    Offspring,
+}
+
 /// Algılanan tekil hedef
 #[derive(Debug, Clone)]
 pub struct PerceivedEntity {
@@ -14,12 +30,29 @@ pub struct PerceivedEntity {
     pub id: usize,
     /// Algılanan canlının türü
     pub species: Species,
+    /// Algılanan canlının beslenme biçimi
+    pub diet: Diet,
     /// Algılanan canlının güç tahmini
     pub power: usize,
+    /// Algılanan canlının cinsiyeti
+    pub sex: Sex,
+    /// Algılanan canlı ebeveyn ya da yavru mu?
+    pub relation: Relation,
+    /// Algılanan canlının son hareket yönü, hiç hareket etmediyse yok
+    pub heading: Option<Direction>,
+    /// Algılanan canlının son tick saldırdığı canlı
+    pub target: Option<usize>,
     /// Algılanan canlının yön ve mesafe bilgisi
     pub steps: Steps,
 }
 
+impl PerceivedEntity {
+    /// Başka türden canlıları avlayıp yiyebilir mi?
+    pub fn is_predator(&self) -> bool {
+        self.diet.eats_meat()
+    }
+}
+
 /// Algılanan yiyecek
 #[derive(Debug, Clone)]
 pub struct PerceivedFood {
@@ -27,6 +60,8 @@ pub struct PerceivedFood {
     pub amount: usize,
     /// Algılanan yemek ceset mi?
     pub is_corpse: bool,
+    /// Ceset ise, cesedin kimliği (`Intent::Eat` için)
+    pub corpse_id: Option<usize>,
     /// Algılanan yemeğin yön ve mesafe bilgisi
     pub steps: Steps,
 }
@@ -40,18 +75,41 @@ pub struct PerceivedWater {
     pub steps: Steps,
 }
 
+/// Duyulan sinyal, görüş dışından da gelebilir
+#[derive(Debug, Clone)]
+pub struct PerceivedSignal {
+    /// Sinyalin türü
+    pub kind: SignalKind,
+    /// Gönderen canlının kimliği
+    pub sender_id: usize,
+    /// Canlının kendi gönderdiği sinyal mi? (aynı çağrıyı tekrarlamamak için duyulur)
+    pub own: bool,
+    /// Gönderen canlının türü
+    pub species: Species,
+    /// Gönderen canlının cinsiyeti
+    pub sex: Sex,
+    /// Sinyalin gönderildiği konum
+    pub pos: Position,
+    /// Gönderildiği konuma uzaklık (Manhattan)
+    pub distance: usize,
+}
+
 /// Canlının görüş açısında olan Yemekler, Diğer canlılar, Gidebiliceği Mesafe
 /// - Bu pozisyonda canlı var mı ve kaç tane var?
 /// - Canlı mı / ceset mi?
 /// - Yakın çevrede kimler var?
 #[derive(Debug, Clone)]
 pub struct Perception {
+    /// Canlının kendi konumu, adımlar buradan başlar
+    pub origin: Position,
     /// Algılanan yemekler
     pub foods: Vec<PerceivedFood>,
     /// Algılanan sular
     pub waters: Vec<PerceivedWater>,
     /// Algılanan canlılar
     pub entities: Vec<PerceivedEntity>,
+    /// Önceki tick gönderilmiş ve menzili buraya yeten sinyaller
+    pub signals: Vec<PerceivedSignal>,
     /// Gidilebilicek mesafe, u8 değeri hangi yöne kaç adımı gidebiliceği simgeler
     pub directions: HashMap<Direction, u8>,
 }
@@ -60,9 +118,11 @@ impl Perception {
     /// Boş bir görüş açısı oluştur
     pub fn empty() -> Self {
         Self {
+            origin: Position::new(0, 0),
             foods: Vec::new(),
             waters: Vec::new(),
             entities: Vec::new(),
+            signals: Vec::new(),
             directions: HashMap::new(),
         }
     }
@@ -71,6 +131,17 @@ impl Perception {
         self.foods.push(PerceivedFood {
             amount,
             is_corpse,
+            corpse_id: None,
+            steps,
+        });
+    }
+
+    /// Algılanan leşi yiyecek olarak ekle
+    pub fn add_corpse(&mut self, corpse_id: usize, meat: usize, steps: Steps) {
+        self.foods.push(PerceivedFood {
+            amount: meat,
+            is_corpse: true,
+            corpse_id: Some(corpse_id),
             steps,
         });
     }
@@ -81,15 +152,58 @@ impl Perception {
     }
 
     /// Algılanan canlıya adım ekle
-    pub fn add_entity(&mut self, id: usize, species: Species, power: usize, steps: Steps) {
+    pub fn add_entity(
+        &mut self,
+        id: usize,
+        species: Species,
+        power: usize,
+        sex: Sex,
+        relation: Relation,
+        steps: Steps,
+    ) {
         self.entities.push(PerceivedEntity {
             id,
             species,
+            // Beslenme biçimi bilinmiyor, her şeyi yiyebilir sayılır
+            diet: Diet::Omnivore,
             power,
+            sex,
+            relation,
+            heading: None,
+            target: None,
             steps,
         });
     }
 
+    /// Listeleri canlıyı merkez alan ızgaraya dök
+    /// Her hedef, adımlarının vardığı hücreye yazılır. Engel kanalı
+    /// haritayı gerektirdiği için boş kalır, `World::build_grid` doldurur.
+    pub fn to_grid(&self, radius: usize, species_count: usize) -> PerceptionGrid {
+        let mut grid = PerceptionGrid::new(radius, species_count);
+        let offset = |steps: &Steps| {
+            let pos = steps.iter().fold(self.origin, |pos, dir| pos + *dir);
+            (pos.x - self.origin.x, pos.y - self.origin.y)
+        };
+        for food in &self.foods {
+            let (dx, dy) = offset(&food.steps);
+            let channel = if food.is_corpse {
+                CORPSE_CHANNEL
+            } else {
+                FOOD_CHANNEL
+            };
+            grid.add(channel, dx, dy, food.amount);
+        }
+        for water in &self.waters {
+            let (dx, dy) = offset(&water.steps);
+            grid.add(WATER_CHANNEL, dx, dy, water.amount);
+        }
+        for other in &self.entities {
+            let (dx, dy) = offset(&other.steps);
+            grid.add(SPECIES_CHANNEL + other.species.0, dx, dy, other.power);
+        }
+        grid
+    }
+
     /// Bir yöne adım ekle veya mevcut adımı güncelle
     pub fn add_direction(&mut self, dir: Direction, distance: u8) {
         self.directions
@@ -99,6 +213,77 @@ impl Perception {
     }
 }
 
+/// Izgaradaki yemek (bitki) kanalı, değer miktardır
+pub const FOOD_CHANNEL: usize = 0;
+/// Izgaradaki leş kanalı, değer et miktarıdır
+pub const CORPSE_CHANNEL: usize = 1;
+/// Izgaradaki su kanalı, değer miktardır
+pub const WATER_CHANNEL: usize = 2;
+/// Izgaradaki engel kanalı, yürünemeyen hücre 1'dir
+pub const OBSTACLE_CHANNEL: usize = 3;
+/// Tür kanallarından önceki sabit kanal sayısı
+/// `Species(n)` kanalı `SPECIES_CHANNEL + n`dir, değer canlının gücüdür.
+pub const SPECIES_CHANNEL: usize = 4;
+
+/// Görüş açısının canlıyı merkez alan, sabit boyutlu ızgara hâli
+/// (2r+1)×(2r+1) hücre, kanal kanal ve satır satır (üstten alta) tutulur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerceptionGrid {
+    /// Izgaranın yarıçapı
+    pub radius: usize,
+    /// Toplam kanal sayısı
+    pub channels: usize,
+    /// Hücre değerleri
+    pub cells: Vec<usize>,
+}
+
+impl PerceptionGrid {
+    /// Boş ızgara, `species_count` kadar tür kanalı ile
+    pub fn new(radius: usize, species_count: usize) -> Self {
+        let side = 2 * radius + 1;
+        let channels = SPECIES_CHANNEL + species_count;
+        Self {
+            radius,
+            channels,
+            cells: vec![0; channels * side * side],
+        }
+    }
+
+    /// Izgaranın kenar uzunluğu
+    pub fn side(&self) -> usize {
+        2 * self.radius + 1
+    }
+
+    /// Göreli konumun kanal içindeki yeri, ızgara dışındaysa `None`
+    fn index(&self, channel: usize, dx: isize, dy: isize) -> Option<usize> {
+        let radius = self.radius as isize;
+        if channel >= self.channels || dx.abs() > radius || dy.abs() > radius {
+            return None;
+        }
+        let side = self.side();
+        Some(channel * side * side + (dy + radius) as usize * side + (dx + radius) as usize)
+    }
+
+    /// Göreli konumdaki değer, ızgara dışı 0
+    pub fn get(&self, channel: usize, dx: isize, dy: isize) -> usize {
+        self.index(channel, dx, dy)
+            .map_or(0, |index| self.cells[index])
+    }
+
+    /// Göreli konuma değer ekle, ızgara dışı yok sayılır
+    pub fn add(&mut self, channel: usize, dx: isize, dy: isize, value: usize) {
+        if let Some(index) = self.index(channel, dx, dy) {
+            self.cells[index] += value;
+        }
+    }
+
+    /// Tek bir kanalın hücreleri
+    pub fn channel(&self, channel: usize) -> &[usize] {
+        let size = self.side() * self.side();
+        &self.cells[channel * size..(channel + 1) * size]
+    }
+}
+
 impl Add<Direction> for PerceivedEntity {
     type Output = Self;
     fn add(mut self, dir: Direction) -> Self {
@@ -7,7 +7,8 @@ pub enum EntityPhase {
     Sleeping { remaining: usize },
 
     /// Ölü, "remaining" tick sonra kaldırılacak
-    Corpse { remaining: usize },
+    /// "meat" yenebilir leş miktarıdır, bittiğinde ceset kaldırılır
+    Corpse { remaining: usize, meat: usize },
 
     /// World tarafından kaldırılacak
     Removed,
@@ -29,11 +30,32 @@ impl EntityPhase {
         matches!(self, EntityPhase::Sleeping { .. })
     }
 
+    /// Uyuyorsa uyandır
+    pub fn wake(&mut self) {
+        if self.is_sleeping() {
+            *self = EntityPhase::Active;
+        }
+    }
+
     /// Kaldırılmasına gerek var mı?
     pub fn need_remove(&self) -> bool {
         matches!(self, EntityPhase::Removed)
     }
 
+    /// Cesetten en fazla "amount" kadar et kopar ve koparılan miktarı döndür
+    /// Et biterse ceset kaldırılmak üzere işaretlenir.
+    pub fn take_meat(&mut self, amount: usize) -> usize {
+        let EntityPhase::Corpse { meat, .. } = self else {
+            return 0;
+        };
+        let taken = (*meat).min(amount);
+        *meat -= taken;
+        if *meat == 0 {
+            *self = EntityPhase::Removed;
+        }
+        taken
+    }
+
     /// World için tick kolaylığı ve otomatik durum güncellemesi
     pub fn tick(&mut self) {
         match self {
@@ -47,7 +69,7 @@ impl EntityPhase {
             }
             // Cesedin ortadan kalkması gereken süreyi düşür,
             // Bittiyse sisteme kaldırası gerektiğini bildir
-            EntityPhase::Corpse { remaining } => {
+            EntityPhase::Corpse { remaining, .. } => {
                 if *remaining > 0 {
                     *remaining -= 1;
                 } else {
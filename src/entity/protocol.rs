@@ -0,0 +1,337 @@
+use crate::{
+    entity::{
+        intent::Intent, lifestate::LifeState, outcome::Outcome, perception::Perception,
+        reproduction::Sex, signal::SignalKind,
+    },
+    map::movement::{Direction, Steps},
+};
+use std::fmt::Write as _;
+
+/// Dış kontrolcüler için satır tabanlı metin protokolü
+///
+/// Her karar için curious şu satırları gönderir:
+/// ```text
+/// state <ajan> age=.. health=.. energy=.. water=.. fatigue=.. max_health=.. max_energy=.. max_water=.. max_fatigue=.. speed=.. vision=.. sex=male|female pregnant=0|1 pos=x,y
+/// food amount=.. corpse=<kimlik>|- steps=U,R,DR
+/// water amount=.. steps=..
+/// entity id=.. species=.. power=.. sex=.. steps=..
+/// signal kind=alarm|mating|food from=<kimlik> species=.. distance=.. pos=x,y
+/// go <sıra>
+/// ```
+/// Kontrolcü tek satırla cevap verir: `<sıra> <niyet>`
+/// ```text
+/// 7 move U R R
+/// 7 eat U R [corpse=<kimlik>]
+/// 7 drink U
+/// 7 mate|attack|flee <kimlik>
+/// 7 idle [süre]
+/// 7 sleep [süre]
+/// 7 signal alarm|mating|food <menzil>
+/// ```
+/// Tick sonunda (cevap beklenmeden) `outcome <ajan> health=.. energy=.. water=.. offspring=.. died=0|1 kills=..` gönderilir.
+/// Boş adım listesi `-` ile yazılır.
+///
+/// Protokol sürümü, el sıkışmada (`curious <sürüm>` / `ready`) kullanılır.
+pub const PROTOCOL_VERSION: usize = 1;
+
+/// Yönün protokoldeki karşılığı
+pub fn direction_token(dir: Direction) -> &'static str {
+    match dir {
+        Direction::Up => "U",
+        Direction::Down => "D",
+        Direction::Left => "L",
+        Direction::Right => "R",
+        Direction::UpLeft => "UL",
+        Direction::UpRight => "UR",
+        Direction::DownLeft => "DL",
+        Direction::DownRight => "DR",
+    }
+}
+
+/// Protokoldeki yönü çöz
+pub fn parse_direction(token: &str) -> Option<Direction> {
+    Some(match token {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        "UL" => Direction::UpLeft,
+        "UR" => Direction::UpRight,
+        "DL" => Direction::DownLeft,
+        "DR" => Direction::DownRight,
+        _ => return None,
+    })
+}
+
+fn steps_token(steps: &Steps) -> String {
+    if steps.is_empty() {
+        return "-".to_string();
+    }
+    steps
+        .iter()
+        .map(|dir| direction_token(*dir))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn sex_token(sex: Sex) -> &'static str {
+    match sex {
+        Sex::Male => "male",
+        Sex::Female => "female",
+    }
+}
+
+/// Bir karar isteğini metne çevir (son satır `go <sıra>`)
+pub fn encode_request(seq: usize, agent: usize, life: &LifeState, view: &Perception) -> String {
+    let genome = &life.genome;
+    let mut text = String::new();
+    let _ = writeln!(
+        text,
+        "state {} age={} health={} energy={} water={} fatigue={} max_health={} max_energy={} max_water={} max_fatigue={} speed={} vision={} sex={} pregnant={} pos={},{}",
+        agent,
+        life.age,
+        life.health,
+        life.energy,
+        life.water,
+        life.fatigue,
+        life.max_health(),
+        genome.max_energy,
+        genome.max_water,
+        genome.max_fatigue,
+        life.speed(),
+        life.vision_range(),
+        sex_token(life.sex),
+        life.is_pregnant() as u8,
+        view.origin.x,
+        view.origin.y
+    );
+    for food in &view.foods {
+        let corpse = food.corpse_id.map_or("-".to_string(), |id| id.to_string());
+        let _ = writeln!(
+            text,
+            "food amount={} corpse={} steps={}",
+            food.amount,
+            corpse,
+            steps_token(&food.steps)
+        );
+    }
+    for water in &view.waters {
+        let _ = writeln!(
+            text,
+            "water amount={} steps={}",
+            water.amount,
+            steps_token(&water.steps)
+        );
+    }
+    for other in &view.entities {
+        let _ = writeln!(
+            text,
+            "entity id={} species={} power={} sex={} steps={}",
+            other.id,
+            other.species.0,
+            other.power,
+            sex_token(other.sex),
+            steps_token(&other.steps)
+        );
+    }
+    for signal in &view.signals {
+        let _ = writeln!(
+            text,
+            "signal kind={} from={} species={} distance={} pos={},{}",
+            signal.kind.name(),
+            signal.sender_id,
+            signal.species.0,
+            signal.distance,
+            signal.pos.x,
+            signal.pos.y
+        );
+    }
+    let _ = writeln!(text, "go {}", seq);
+    text
+}
+
+/// Tick sonucunu metne çevir
+pub fn encode_outcome(agent: usize, outcome: &Outcome) -> String {
+    format!(
+        "outcome {} health={} energy={} water={} offspring={} died={} kills={}\n",
+        agent,
+        outcome.health,
+        outcome.energy,
+        outcome.water,
+        outcome.offspring,
+        outcome.died as u8,
+        outcome.kills
+    )
+}
+
+/// Cevap satırını çöz: `<sıra> <niyet> ...`
+pub fn parse_reply(line: &str) -> Result<(usize, Intent), String> {
+    let mut tokens = line.split_whitespace();
+    let seq = tokens
+        .next()
+        .and_then(|t| t.parse::<usize>().ok())
+        .ok_or_else(|| format!("sıra numarası bekleniyordu: '{}'", line))?;
+    let verb = tokens
+        .next()
+        .ok_or_else(|| format!("niyet bekleniyordu: '{}'", line))?;
+    let rest: Vec<&str> = tokens.collect();
+
+    let parse_steps = |tokens: &[&str]| -> Result<Steps, String> {
+        let mut steps = Steps::empty();
+        for token in tokens.iter().flat_map(|t| t.split(',')) {
+            if token == "-" || token.is_empty() {
+                continue;
+            }
+            steps += parse_direction(token).ok_or_else(|| format!("geçersiz yön '{}'", token))?;
+        }
+        Ok(steps)
+    };
+    let target = || -> Result<usize, String> {
+        rest.first()
+            .and_then(|t| t.parse().ok())
+            .ok_or_else(|| format!("{} için hedef kimliği bekleniyordu", verb))
+    };
+    let duration = || -> Result<usize, String> {
+        rest.first().map_or(Ok(1), |t| {
+            t.parse().map_err(|_| format!("geçersiz süre '{}'", t))
+        })
+    };
+
+    let intent = match verb {
+        "move" => Intent::Move {
+            steps: parse_steps(&rest)?,
+        },
+        "eat" => {
+            let corpse_id = match rest.iter().find_map(|t| t.strip_prefix("corpse=")) {
+                Some(id) => Some(id.parse().map_err(|_| format!("geçersiz leş '{}'", id))?),
+                None => None,
+            };
+            let steps: Vec<&str> = rest
+                .iter()
+                .copied()
+                .filter(|t| !t.starts_with("corpse="))
+                .collect();
+            Intent::Eat {
+                at: parse_steps(&steps)?,
+                corpse_id,
+            }
+        }
+        "drink" => Intent::Drink {
+            at: parse_steps(&rest)?,
+        },
+        "mate" => Intent::Mate {
+            target_id: target()?,
+        },
+        "attack" => Intent::Attack {
+            target_id: target()?,
+        },
+        "flee" => Intent::Flee {
+            target_id: target()?,
+        },
+        "idle" => Intent::Idle {
+            duration: duration()?,
+        },
+        "sleep" => Intent::Sleep {
+            duration: duration()?,
+        },
+        "signal" => {
+            let kind = rest
+                .first()
+                .and_then(|t| SignalKind::from_name(t))
+                .ok_or_else(|| format!("{} için sinyal türü bekleniyordu", verb))?;
+            let range = rest
+                .get(1)
+                .and_then(|t| t.parse().ok())
+                .ok_or_else(|| format!("{} için menzil bekleniyordu", verb))?;
+            Intent::Signal { kind, range }
+        }
+        _ => return Err(format!("bilinmeyen niyet '{}'", verb)),
+    };
+    Ok((seq, intent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_replies() {
+        assert_eq!(
+            parse_reply("3 move U,R DL"),
+            Ok((
+                3,
+                Intent::Move {
+                    steps: Steps::new(vec![Direction::Up, Direction::Right, Direction::DownLeft]),
+                }
+            ))
+        );
+        assert_eq!(
+            parse_reply("4 eat R corpse=17"),
+            Ok((
+                4,
+                Intent::Eat {
+                    at: Steps::new(vec![Direction::Right]),
+                    corpse_id: Some(17),
+                }
+            ))
+        );
+        assert_eq!(
+            parse_reply("5 drink -"),
+            Ok((5, Intent::Drink { at: Steps::empty() }))
+        );
+        assert_eq!(
+            parse_reply("6 attack 12"),
+            Ok((6, Intent::Attack { target_id: 12 }))
+        );
+        assert_eq!(parse_reply("7 idle"), Ok((7, Intent::Idle { duration: 1 })));
+        assert_eq!(
+            parse_reply("8 sleep 4"),
+            Ok((8, Intent::Sleep { duration: 4 }))
+        );
+        assert_eq!(
+            parse_reply("9 signal alarm 6"),
+            Ok((
+                9,
+                Intent::Signal {
+                    kind: SignalKind::Alarm,
+                    range: 6,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_replies() {
+        for line in [
+            "",
+            "move U",
+            "1",
+            "1 dance",
+            "1 move X",
+            "1 eat corpse=abc",
+            "1 mate",
+            "1 flee kim",
+            "1 sleep uzun",
+            "1 signal shout 3",
+            "1 signal food",
+        ] {
+            assert!(parse_reply(line).is_err(), "'{}' kabul edildi", line);
+        }
+    }
+
+    #[test]
+    fn directions_round_trip() {
+        let steps = Steps::new(vec![
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownLeft,
+            Direction::DownRight,
+        ]);
+        let line = format!("1 move {}", steps_token(&steps));
+        assert_eq!(parse_reply(&line), Ok((1, Intent::Move { steps })));
+    }
+}
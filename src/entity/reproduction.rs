@@ -0,0 +1,61 @@
+use crate::{entity::genome::Genome, gen_range};
+
+/// Canlının cinsiyeti
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sex {
+    Male,
+    Female,
+}
+
+impl Sex {
+    /// Eşit ihtimalle rastgele cinsiyet
+    pub fn random() -> Sex {
+        if gen_range(0, 1) == 0 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Karşı cinsiyet
+    pub fn opposite(&self) -> Sex {
+        match self {
+            Sex::Male => Sex::Female,
+            Sex::Female => Sex::Male,
+        }
+    }
+}
+
+/// Dişinin taşıdığı gebelik
+/// Babanın genomu döllenme anında saklanır,
+/// doğumda baba ölmüş olsa bile yavru ondan kalıtım alır.
+#[derive(Debug, Clone)]
+pub struct Gestation {
+    /// Doğuma kalan tick
+    pub remaining: usize,
+    /// Babanın kimliği
+    pub father_id: usize,
+    /// Babanın genomu
+    pub father: Genome,
+}
+
+impl Gestation {
+    /// Yeni gebelik başlat
+    pub fn new(duration: usize, father_id: usize, father: Genome) -> Self {
+        Self {
+            remaining: duration,
+            father_id,
+            father,
+        }
+    }
+
+    /// Bir tick ilerlet
+    pub fn tick(&mut self) {
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+
+    /// Doğum zamanı geldi mi?
+    pub fn is_due(&self) -> bool {
+        self.remaining == 0
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    entity::{reproduction::Sex, species::Species},
+    map::movement::Position,
+};
+
+/// Bir sinyalin en fazla ulaşabileceği mesafe (Manhattan)
+pub const MAX_SIGNAL_RANGE: usize = 12;
+
+/// Canlıların birbirine gönderebildiği çağrılar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalKind {
+    /// Tehlike çağrısı, avcı görüldü
+    Alarm,
+    /// Eş arama çağrısı
+    Mating,
+    /// Yemek bulundu çağrısı
+    Food,
+}
+
+impl SignalKind {
+    /// Sinyalin metin karşılığı (protokol ve loglar için)
+    pub fn name(&self) -> &'static str {
+        match self {
+            SignalKind::Alarm => "alarm",
+            SignalKind::Mating => "mating",
+            SignalKind::Food => "food",
+        }
+    }
+
+    /// Metinden sinyal türü
+    pub fn from_name(name: &str) -> Option<SignalKind> {
+        match name {
+            "alarm" => Some(SignalKind::Alarm),
+            "mating" => Some(SignalKind::Mating),
+            "food" => Some(SignalKind::Food),
+            _ => None,
+        }
+    }
+}
+
+/// Bir tick içinde gönderilen sinyal
+/// World tarafından tutulur ve bir sonraki tick menzildeki canlılara iletilir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signal {
+    /// Sinyalin türü
+    pub kind: SignalKind,
+    /// Gönderen canlının kimliği
+    pub sender_id: usize,
+    /// Gönderen canlının türü
+    pub species: Species,
+    /// Gönderen canlının cinsiyeti (eş çağrısı için)
+    pub sex: Sex,
+    /// Gönderildiği konum
+    pub pos: Position,
+    /// Menzil, `MAX_SIGNAL_RANGE` ile sınırlıdır
+    pub range: usize,
+}
@@ -1,6 +1,156 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Species {
+use crate::{
+    creatures::config::default_species_configs,
+    entity::{Entity, genome::Genome},
+};
+use std::rc::Rc;
+
+/// Bir türden yeni, yetişkin bir canlı üreten fonksiyon
+pub type EntityFactory = Rc<dyn Fn() -> Box<dyn Entity>>;
+
+/// Kayıtlı bir türün kimliği
+/// `SpeciesRegistry` içindeki sırası ile eşleşir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Species(pub usize);
+
+impl Species {
+    /// Hazır gelen türler, `SpeciesRegistry::default` bu sırayla kaydeder
+    pub const HERBIVORE: Species = Species(0);
+    pub const CARNIVORE: Species = Species(1);
+    pub const OMNIVORE: Species = Species(2);
+}
+
+/// Türün beslenme biçimi
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diet {
+    /// Sadece bitki (harita yemekleri)
     Herbivore,
+    /// Sadece et (leş)
     Carnivore,
+    /// İkisi de
     Omnivore,
 }
+
+impl Diet {
+    /// Bitki yiyebilir mi?
+    pub fn eats_plants(&self) -> bool {
+        matches!(self, Diet::Herbivore | Diet::Omnivore)
+    }
+
+    /// Et yiyebilir mi?
+    pub fn eats_meat(&self) -> bool {
+        matches!(self, Diet::Carnivore | Diet::Omnivore)
+    }
+}
+
+/// Kayıtlı bir türün bilgileri
+#[derive(Debug, Clone)]
+pub struct SpeciesInfo {
+    /// Türün adı (benzersiz)
+    pub name: String,
+    /// Haritada gösterilecek karakter
+    pub glyph: char,
+    /// Haritada gösterilecek renk (R, G, B)
+    pub color: (u8, u8, u8),
+    /// Beslenme biçimi
+    pub diet: Diet,
+    /// Türün varsayılan özellikleri
+    pub genome: Genome,
+}
+
+/// Çalışma anında genişletilebilen tür listesi
+/// `curious` dışında yazılan canlılar da buraya kaydolup kimlik alır.
+/// Fabrikası kayıtlı türler World üzerinden isimle doğurulabilir.
+#[derive(Clone)]
+pub struct SpeciesRegistry {
+    entries: Vec<SpeciesInfo>,
+    factories: Vec<Option<EntityFactory>>,
+}
+
+impl SpeciesRegistry {
+    /// Boş bir kayıt listesi
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+            factories: Vec::new(),
+        }
+    }
+
+    /// Yeni tür kaydet ve kimliğini döndür
+    /// Aynı isimde bir tür varsa bilgileri güncellenir, kimliği değişmez.
+    pub fn register(&mut self, info: SpeciesInfo) -> Species {
+        if let Some(species) = self.by_name(&info.name) {
+            self.entries[species.0] = info;
+            return species;
+        }
+        self.entries.push(info);
+        self.factories.push(None);
+        Species(self.entries.len() - 1)
+    }
+
+    /// Türü bir fabrika ile birlikte kaydet
+    pub fn register_with_factory(&mut self, info: SpeciesInfo, factory: EntityFactory) -> Species {
+        let species = self.register(info);
+        self.set_factory(species, factory);
+        species
+    }
+
+    /// Kayıtlı türün fabrikasını ata (varsa değiştir)
+    pub fn set_factory(&mut self, species: Species, factory: EntityFactory) {
+        if let Some(slot) = self.factories.get_mut(species.0) {
+            *slot = Some(factory);
+        }
+    }
+
+    /// Türün fabrikası
+    pub fn factory(&self, species: Species) -> Option<&EntityFactory> {
+        self.factories.get(species.0).and_then(Option::as_ref)
+    }
+
+    /// Türün bilgileri
+    pub fn get(&self, species: Species) -> Option<&SpeciesInfo> {
+        self.entries.get(species.0)
+    }
+
+    /// İsimden tür kimliği bul
+    pub fn by_name(&self, name: &str) -> Option<Species> {
+        self.entries
+            .iter()
+            .position(|info| info.name == name)
+            .map(Species)
+    }
+
+    /// Türün adı, kayıtlı değilse "?"
+    pub fn name(&self, species: Species) -> &str {
+        self.get(species).map_or("?", |info| info.name.as_str())
+    }
+
+    /// Kayıtlı tür sayısı
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Hiç tür kayıtlı değil mi?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Tüm türler, kimlikleri ile birlikte
+    pub fn iter(&self) -> impl Iterator<Item = (Species, &SpeciesInfo)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, info)| (Species(i), info))
+    }
+}
+
+impl Default for SpeciesRegistry {
+    /// Hazır türler ile dolu kayıt listesi
+    /// Türler gömülü `config/species.cfg` dosyasından okunur.
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for config in default_species_configs() {
+            config.register(&mut registry);
+        }
+        registry
+    }
+}
@@ -41,11 +41,13 @@ pub fn next_rand() -> u64 {
 
 /// [min, max] aralığında sayı üretir
 pub fn gen_range(min: isize, max: isize) -> isize {
-    let range = (max - min).abs() as u64;
+    let range = (max - min).unsigned_abs() as u64;
     if range == 0 {
         return min;
     }
-    let rand_val = next_rand() % (range + 1);
+    // LCG'nin alt bitlerinin periyodu kısadır (en alttaki bit her çağrıda değişir),
+    // ardışık çağrılar birbirine bağlı kalmasın diye üst bitler kullanılır
+    let rand_val = (next_rand() >> 32) % (range + 1);
     min + rand_val as isize
 }
 
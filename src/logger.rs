@@ -1,5 +1,5 @@
 use std::{
-    fs::{create_dir_all, File, OpenOptions},
+    fs::{File, OpenOptions, create_dir_all},
     io::Write,
     path::Path,
     time::{SystemTime, UNIX_EPOCH},
@@ -78,10 +78,6 @@ impl Logger {
             let _ = create_dir_all(parent);
         }
 
-        OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(path)
-            .ok()
+        OpenOptions::new().create(true).append(true).open(path).ok()
     }
 }
@@ -1,61 +1,56 @@
 use curious::{
-    creatures::carnivore::CarnivoreEntity,
-    creatures::herbivore::HerbivoreEntity,
-    creatures::omnivore::OmnivoreEntity,
-    entity::{perception::Perception, phase::EntityPhase},
-    map::movement::Position,
+    creatures::config::{DEFAULT_SPECIES_CONFIG, load_species_config, parse_species_config},
+    entity::phase::EntityPhase,
     set_global_seed_with_time,
-    world::{EntitySlot, World},
+    world::World,
 };
-use std::{thread, time::Duration};
+use std::{path::Path, thread, time::Duration};
+
+/// Tür tanımları, dosya yoksa derlemeye gömülü kopya kullanılır
+const SPECIES_CONFIG: &str = "config/species.cfg";
 
 fn main() {
     // RNG için seed'i zaman damgası olarak günceller
     set_global_seed_with_time();
-    let entities: Vec<EntitySlot> = vec![
-        EntitySlot::new(
-            1,
-            (-15isize, -15isize).into(),
-            EntityPhase::Active,
-            Box::new(HerbivoreEntity::default()),
-        ),
-        EntitySlot::new(
-            2,
-            (-14isize, -15isize).into(),
-            EntityPhase::Active,
-            Box::new(HerbivoreEntity::default()),
-        ),
-        EntitySlot::new(
-            3,
-            (14isize, -15isize).into(),
-            EntityPhase::Active,
-            Box::new(CarnivoreEntity::default()),
-        ),
-        EntitySlot::new(
-            4,
-            (15isize, -15isize).into(),
-            EntityPhase::Active,
-            Box::new(OmnivoreEntity::default()),
-        ),
-        EntitySlot::new(
-            5,
-            (-15isize, 15isize).into(),
-            EntityPhase::Active,
-            Box::new(CarnivoreEntity::default()),
-        ),
-        EntitySlot::new(
-            6,
-            (-14isize, 15isize).into(),
-            EntityPhase::Active,
-            Box::new(OmnivoreEntity::default()),
-        ),
-    ];
+
+    // Tür tanımlarını oku, hatalı satırı göstererek çık
+    let configs = if Path::new(SPECIES_CONFIG).exists() {
+        load_species_config(SPECIES_CONFIG)
+    } else {
+        parse_species_config(DEFAULT_SPECIES_CONFIG)
+    };
+    let configs = match configs {
+        Ok(configs) => configs,
+        Err(err) => {
+            eprintln!("{}: {}", SPECIES_CONFIG, err);
+            std::process::exit(1);
+        }
+    };
+
     // İnteraktif dünya
-    let mut world = World::new(-15, 14, -15, 14, entities);
+    let mut world = World::new(-15, 14, -15, 14, Vec::new());
+    for config in &configs {
+        config.register(&mut world.species);
+    }
+
+    // Başlangıç canlıları
+    let start: [(&str, (isize, isize)); 6] = [
+        ("herbivore", (-15, -15)),
+        ("herbivore", (-14, -15)),
+        ("carnivore", (14, -15)),
+        ("omnivore", (14, -14)),
+        ("carnivore", (-15, 14)),
+        ("omnivore", (-14, 14)),
+    ];
+    for (name, pos) in start {
+        if let Err(err) = world.spawn(name, pos.into()) {
+            eprintln!("{} {:?}: {}", name, pos, err);
+        }
+    }
     // İnteraktif dünya sayacı
     let mut tick_counter: usize = 0;
     loop {
-        print!("\x1B[2J\x1B[1;1H\n");
+        println!("\x1B[2J\x1B[1;1H");
         world.tick();
         tick_counter += 1;
         print_map(&world, tick_counter);
@@ -83,14 +78,15 @@ pub fn print_map(world: &World, tick: usize) {
                 match slot.phase {
                     // ANSI TrueColor formatı: \x1b[38;2;R;G;Bm
                     // \x1b[0m kodu ise rengi sıfırlamak içindir
-                    EntityPhase::Active => {
-                        // Türüne göre renk: Etçil kırmızı, Otçul yeşil, Hepçil mavi
-                        let (r, g, b) = match slot.base.species() {
-                            curious::entity::species::Species::Carnivore => (220, 40, 40),
-                            curious::entity::species::Species::Herbivore => (40, 200, 40),
-                            curious::entity::species::Species::Omnivore => (60, 120, 220),
-                        };
-                        print!("\x1b[38;2;{};{};{}m@ \x1b[0m", r, g, b);
+                    EntityPhase::Active | EntityPhase::Sleeping { .. } => {
+                        // Türüne göre renk ve karakter kayıt listesinden gelir
+                        let (glyph, (r, g, b)) = world
+                            .species
+                            .get(slot.base.species())
+                            .map_or(('?', (200, 200, 200)), |info| (info.glyph, info.color));
+                        // Uyuyan canlı "z" ile gösterilir
+                        let glyph = if slot.phase.is_sleeping() { 'z' } else { glyph };
+                        print!("\x1b[38;2;{};{};{}m{} \x1b[0m", r, g, b, glyph);
                     } // Canlı
                     EntityPhase::Corpse { .. } => {
                         // Ceset turuncu
@@ -115,8 +111,8 @@ pub fn print_map(world: &World, tick: usize) {
         if let Some(slot) = world.entities.get(entity_index) {
             let life = slot.entity().life();
             print!(
-                "  {:?} | @{:<2} {:?} HP:{:<3} EN:{:<3} AGE:{:<3} Ph:{:?} ",
-                slot.base.species(),
+                "  {} | @{:<2} {:?} HP:{:<3} EN:{:<3} AGE:{:<3} Ph:{:?} ",
+                world.species.name(slot.base.species()),
                 slot.id,
                 slot.pos,
                 life.health,
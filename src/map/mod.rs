@@ -4,7 +4,7 @@ pub mod movement;
 use std::collections::{HashMap, VecDeque};
 
 use crate::{
-    CHUNK_SIZE, gen_range,
+    CHUNK_SIZE,
     map::{
         cell::Cell,
         movement::{DIRECTION_ARRAY, Direction, Position, Steps},
@@ -297,10 +297,10 @@ impl Map {
                     continue;
                 }
 
-                if let Some(Cell::Food { amount }) = self.cell(pos) {
-                    if let Some(steps) = self.bfs_steps_to(center, pos, radius) {
-                        result.push((pos, steps, *amount));
-                    }
+                if let Some(Cell::Food { amount }) = self.cell(pos)
+                    && let Some(steps) = self.bfs_steps_to(center, pos, radius)
+                {
+                    result.push((pos, steps, *amount));
                 }
             }
         }
@@ -328,10 +328,10 @@ impl Map {
                     continue;
                 }
 
-                if let Some(Cell::Water { amount }) = self.cell(pos) {
-                    if let Some(steps) = self.bfs_steps_to(center, pos, radius) {
-                        result.push((pos, steps, *amount));
-                    }
+                if let Some(Cell::Water { amount }) = self.cell(pos)
+                    && let Some(steps) = self.bfs_steps_to(center, pos, radius)
+                {
+                    result.push((pos, steps, *amount));
                 }
             }
         }
@@ -370,7 +370,7 @@ impl Map {
                 if !self.in_bounds(world_pos)
                     || !self
                         .cell(world_pos)
-                        .map_or(true, |c| matches!(c, Cell::Empty))
+                        .is_none_or(|c| matches!(c, Cell::Empty))
                 {
                     continue;
                 }
@@ -26,6 +26,22 @@ impl Position {
     pub fn distance_to(&self, other: Position) -> usize {
         ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
     }
+
+    /// Hedefe doğru atılacak tek adımın yönü (çapraz dahil)
+    /// Aynı konumdaysa `None` döner.
+    pub fn direction_to(&self, other: Position) -> Option<Direction> {
+        match ((other.x - self.x).signum(), (other.y - self.y).signum()) {
+            (0, -1) => Some(Direction::Up),
+            (0, 1) => Some(Direction::Down),
+            (-1, 0) => Some(Direction::Left),
+            (1, 0) => Some(Direction::Right),
+            (-1, -1) => Some(Direction::UpLeft),
+            (1, -1) => Some(Direction::UpRight),
+            (-1, 1) => Some(Direction::DownLeft),
+            (1, 1) => Some(Direction::DownRight),
+            _ => None,
+        }
+    }
 }
 
 impl From<(isize, isize)> for Position {
@@ -46,6 +62,28 @@ pub enum Direction {
     DownRight,
 }
 
+impl Direction {
+    /// Yönün x ve y bileşenleri (-1, 0 ya da 1)
+    pub fn offset(&self) -> (isize, isize) {
+        let pos = Position::new(0, 0) + *self;
+        (pos.x, pos.y)
+    }
+
+    /// Ters yön
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+}
+
 pub const DIRECTION_ARRAY: [Direction; 8] = [
     Direction::Down,
     Direction::Up,
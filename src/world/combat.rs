@@ -0,0 +1,162 @@
+use crate::{entity::lifestate::LifeState, entity::species::Species, gen_range};
+
+/// En düşük ve en yüksek isabet ihtimali (yüzde)
+const MIN_HIT_CHANCE: isize = 10;
+const MAX_HIT_CHANCE: isize = 95;
+/// Çevikliği eşit iki canlı arasında ki isabet ihtimali (yüzde)
+const BASE_HIT_CHANCE: isize = 75;
+
+/// Tek bir vuruşun sonucu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrikeOutcome {
+    /// Vuruş isabet etti mi?
+    pub hit: bool,
+    /// Verilen hasar (ıskalandıysa 0)
+    pub damage: usize,
+    /// Vuruşun saldırana enerji maliyeti
+    pub cost: usize,
+}
+
+/// Saldırı sonucunda ölen canlının kaydı
+/// Avlanma başarısını ölçmek için World tarafından tutulur.
+#[derive(Debug, Clone)]
+pub struct KillRecord {
+    /// Ölümün gerçekleştiği tick
+    pub tick: usize,
+    /// Öldüren canlının kimliği
+    pub killer_id: usize,
+    /// Öldüren canlının türü
+    pub killer_species: Species,
+    /// Ölen canlının kimliği
+    pub victim_id: usize,
+    /// Ölen canlının türü
+    pub victim_species: Species,
+    /// Aynı tick içinde saldıran, öldürenle aynı türden sürü (öldüren dahil)
+    pub pack: Vec<usize>,
+}
+
+/// İsabet ihtimali (yüzde)
+/// Her çeviklik farkı ihtimali 5 puan değiştirir.
+pub fn hit_chance(attacker: &LifeState, defender: &LifeState) -> isize {
+    let diff = attacker.genome.agility as isize - defender.genome.agility as isize;
+    (BASE_HIT_CHANCE + diff * 5).clamp(MIN_HIT_CHANCE, MAX_HIT_CHANCE)
+}
+
+/// Vuruşun ortalama hasarı
+/// Saldırı savunmaya göre ne kadar büyükse o kadar etkili olur, en az 1.
+pub fn base_damage(attacker: &LifeState, defender: &LifeState) -> usize {
+    let attack = attacker.attack();
+    let total = (attack + defender.genome.defense).max(1);
+    (attack * attack / total).max(1)
+}
+
+/// Vuruşun saldırana enerji maliyeti
+pub fn strike_cost(attacker: &LifeState) -> usize {
+    1 + attacker.attack() / 4
+}
+
+/// World RNG'si ile tek bir vuruşu zarlar
+/// Hasar ortalamanın %75'i ile %125'i arasında değişir.
+pub fn strike(attacker: &LifeState, defender: &LifeState) -> StrikeOutcome {
+    let cost = strike_cost(attacker);
+    if gen_range(1, 100) > hit_chance(attacker, defender) {
+        return StrikeOutcome {
+            hit: false,
+            damage: 0,
+            cost,
+        };
+    }
+
+    let base = base_damage(attacker, defender) as isize;
+    let spread = base / 4;
+    let damage = gen_range(base - spread, base + spread).max(1) as usize;
+    StrikeOutcome {
+        hit: true,
+        damage,
+        cost,
+    }
+}
+
+/// Uyuyan hedefe vuruş
+/// Hedef kaçamaz, vuruş her zaman isabet eder.
+pub fn ambush(attacker: &LifeState, defender: &LifeState) -> StrikeOutcome {
+    StrikeOutcome {
+        hit: true,
+        damage: base_damage(attacker, defender),
+        cost: strike_cost(attacker),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{creatures::config::default_species, set_global_seed};
+
+    fn fighter(attack: usize, defense: usize, agility: usize) -> LifeState {
+        let mut genome = default_species("carnivore").info.genome.clone();
+        genome.attack = attack;
+        genome.defense = defense;
+        genome.agility = agility;
+        LifeState::new(genome)
+    }
+
+    #[test]
+    fn defense_lowers_damage() {
+        let attacker = fighter(12, 5, 5);
+        let mut last = usize::MAX;
+        for defense in [0, 4, 12, 40] {
+            let damage = base_damage(&attacker, &fighter(1, defense, 5));
+            assert!(damage <= last, "savunma {} hasarı artırdı", defense);
+            last = damage;
+        }
+        assert!(
+            base_damage(&attacker, &fighter(1, 0, 5)) > base_damage(&attacker, &fighter(1, 40, 5))
+        );
+        // Saldırısız ve savunmasız eşleşmede bile hasar en az 1
+        assert_eq!(base_damage(&fighter(0, 0, 5), &fighter(0, 0, 5)), 1);
+    }
+
+    #[test]
+    fn strike_stays_within_spread() {
+        set_global_seed(27);
+        let attacker = fighter(12, 5, 5);
+        let defender = fighter(4, 6, 5);
+        let base = base_damage(&attacker, &defender);
+        for _ in 0..200 {
+            let outcome = strike(&attacker, &defender);
+            assert_eq!(outcome.cost, strike_cost(&attacker));
+            if outcome.hit {
+                assert!(outcome.damage >= base - base / 4 && outcome.damage <= base + base / 4);
+            }
+        }
+    }
+
+    #[test]
+    fn miss_deals_no_damage() {
+        set_global_seed(27);
+        // Çok daha çevik hedefe isabet ihtimali en düşük seviyededir
+        let attacker = fighter(12, 5, 0);
+        let defender = fighter(4, 6, 40);
+        assert_eq!(hit_chance(&attacker, &defender), MIN_HIT_CHANCE);
+        let outcomes: Vec<StrikeOutcome> = (0..200).map(|_| strike(&attacker, &defender)).collect();
+        assert!(outcomes.iter().any(|outcome| !outcome.hit));
+        for outcome in outcomes.iter().filter(|outcome| !outcome.hit) {
+            assert_eq!(outcome.damage, 0);
+        }
+    }
+
+    #[test]
+    fn damage_never_underflows_health() {
+        set_global_seed(27);
+        let attacker = fighter(40, 5, 40);
+        let mut defender = LifeState::new(default_species("herbivore").info.genome.clone());
+        defender.health = 1;
+        for _ in 0..20 {
+            let outcome = strike(&attacker, &defender);
+            defender.take_damage(outcome.damage);
+        }
+        defender.take_damage(ambush(&attacker, &defender).damage);
+        assert_eq!(defender.health, 0);
+        assert!(!defender.is_alive());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    entity::{
+        Entity,
+        genome::Genome,
+        instinct::Behaviour,
+        intent::Intent,
+        learning::reward,
+        lifestate::LifeState,
+        outcome::Outcome,
+        perception::{Perception, PerceptionGrid},
+        species::Species,
+    },
+    set_global_seed,
+    world::{EntitySlot, World},
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Dışarıdan yönetilen canlının sıradaki niyeti ve tick sonucu
+#[derive(Default)]
+struct Control {
+    action: Option<Intent>,
+    reward: f32,
+}
+
+/// Kararı dışarıya bırakan sarmalayıcı
+/// Niyet dışında her şeyi asıl canlıya devreder, yavrular sarmalanmaz.
+struct Puppet {
+    inner: Box<dyn Entity>,
+    control: Rc<RefCell<Control>>,
+}
+
+impl Entity for Puppet {
+    fn life(&self) -> &LifeState {
+        self.inner.life()
+    }
+
+    fn life_mut(&mut self) -> &mut LifeState {
+        self.inner.life_mut()
+    }
+
+    fn species(&self) -> Species {
+        self.inner.species()
+    }
+
+    fn behaviour(&self) -> Behaviour {
+        self.inner.behaviour()
+    }
+
+    fn make_intent(&self, _view: Perception) -> Intent {
+        self.control
+            .borrow_mut()
+            .action
+            .take()
+            .unwrap_or(Intent::Idle { duration: 1 })
+    }
+
+    fn observe(&mut self, view: &Perception) {
+        self.inner.observe(view);
+    }
+
+    fn outcome(&mut self, outcome: &Outcome) {
+        self.control.borrow_mut().reward += reward(outcome);
+        self.inner.outcome(outcome);
+    }
+
+    fn tick(&mut self) {
+        self.inner.tick();
+    }
+
+    fn sleep_tick(&mut self) {
+        self.inner.sleep_tick();
+    }
+
+    fn accept_mate(&self, suitor: &LifeState) -> bool {
+        self.inner.accept_mate(suitor)
+    }
+
+    fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+        self.inner.reproduce(partner)
+    }
+}
+
+/// Yönetilen bir canlının gözlemi
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    /// Canlının kimliği
+    pub id: usize,
+    /// Canlı hâlâ yaşıyor mu? Ölüyse ızgara boştur.
+    pub alive: bool,
+    /// Canlıyı merkez alan görüş ızgarası
+    pub grid: PerceptionGrid,
+    /// Enerji, su, can ve yorgunluk oranları (0..=1)
+    pub stats: [f32; 4],
+}
+
+/// `World` etrafında pekiştirmeli öğrenme ortamı
+///
+/// Seçilen canlıların niyetleri `step` ile dışarıdan verilir,
+/// diğer canlılar kendi kararlarını vermeye devam eder.
+/// Ödül `learning::reward` ile tick sonucundan hesaplanır.
+pub struct Environment {
+    /// Simülasyon
+    pub world: World,
+    /// Gözlem ızgarasının yarıçapı
+    pub radius: usize,
+    /// Bölüm en fazla kaç adım sürer
+    pub max_steps: Option<usize>,
+    /// Bölümde atılan adım sayısı
+    pub steps: usize,
+    /// Dünyayı kuran ve yönetilecek canlıların kimliklerini döndüren fonksiyon
+    setup: Box<dyn Fn() -> (World, Vec<usize>)>,
+    /// Yönetilen canlılar, `step`e verilen niyetlerle aynı sırada
+    controlled: Vec<(usize, Rc<RefCell<Control>>)>,
+}
+
+impl Environment {
+    /// Ortamı kur ve verilen tohum ile ilk bölümü başlat
+    pub fn new(
+        radius: usize,
+        seed: u64,
+        setup: impl Fn() -> (World, Vec<usize>) + 'static,
+    ) -> (Self, Vec<Observation>) {
+        set_global_seed(seed);
+        let (world, ids) = setup();
+        let mut env = Self {
+            world,
+            radius,
+            max_steps: None,
+            steps: 0,
+            setup: Box::new(setup),
+            controlled: Vec::new(),
+        };
+        env.take_control(ids);
+        let observations = env.observe();
+        (env, observations)
+    }
+
+    /// Aynı kurulumla yeni bir bölüm başlat
+    /// Aynı tohum her zaman aynı bölümü üretir.
+    pub fn reset(&mut self, seed: u64) -> Vec<Observation> {
+        set_global_seed(seed);
+        let (world, ids) = (self.setup)();
+        self.world = world;
+        self.steps = 0;
+        self.take_control(ids);
+        self.observe()
+    }
+
+    /// Yönetilen canlıların kimlikleri
+    pub fn controlled(&self) -> Vec<usize> {
+        self.controlled.iter().map(|(id, _)| *id).collect()
+    }
+
+    /// Niyetleri uygula ve dünyayı bir tick ilerlet
+    ///
+    /// `actions` yönetilen canlılarla aynı sıradadır, eksik niyet `Idle` sayılır.
+    /// Uyuyan ya da ölü canlının niyeti yok sayılır.
+    /// Bölüm, yönetilen canlıların hepsi öldüğünde ya da `max_steps` dolduğunda biter.
+    pub fn step(&mut self, actions: &[Intent]) -> (Vec<Observation>, Vec<f32>, bool) {
+        for (index, (_, control)) in self.controlled.iter().enumerate() {
+            let mut control = control.borrow_mut();
+            control.action = actions.get(index).cloned();
+            control.reward = 0.0;
+        }
+
+        self.world.tick();
+        self.steps += 1;
+
+        let rewards = self
+            .controlled
+            .iter()
+            .map(|(_, control)| control.borrow().reward)
+            .collect();
+        let observations = self.observe();
+        let done = observations.iter().all(|obs| !obs.alive)
+            || self.max_steps.is_some_and(|max| self.steps >= max);
+        (observations, rewards, done)
+    }
+
+    /// Kimliği verilen canlıları sarmala, kararlarını dışarı bırak
+    fn take_control(&mut self, ids: Vec<usize>) {
+        self.controlled.clear();
+        for id in ids {
+            let Some(index) = self.world.entities.iter().position(|slot| slot.id == id) else {
+                continue;
+            };
+            let control = Rc::new(RefCell::new(Control::default()));
+            let slot = self.world.entities.remove(index);
+            let puppet = Puppet {
+                inner: slot.base,
+                control: control.clone(),
+            };
+            let mut wrapped = EntitySlot::new(slot.id, slot.pos, slot.phase, Box::new(puppet));
+            wrapped.killed_by = slot.killed_by;
+            wrapped.mother_id = slot.mother_id;
+            wrapped.father_id = slot.father_id;
+            wrapped.heading = slot.heading;
+            wrapped.target = slot.target;
+            wrapped.hunters = slot.hunters;
+            self.world.entities.insert(index, wrapped);
+            self.controlled.push((id, control));
+        }
+    }
+
+    /// Yönetilen canlıların gözlemleri
+    pub fn observe(&self) -> Vec<Observation> {
+        self.controlled
+            .iter()
+            .map(|(id, _)| self.observation(*id))
+            .collect()
+    }
+
+    /// Tek bir canlının gözlemi
+    pub fn observation(&self, id: usize) -> Observation {
+        let mut observation = Observation {
+            id,
+            alive: false,
+            grid: PerceptionGrid::new(self.radius, self.world.species.len()),
+            stats: [0.0; 4],
+        };
+        let Some(slot) = self.world.slot(id) else {
+            return observation;
+        };
+        if !(slot.phase.is_active() || slot.phase.is_sleeping()) {
+            return observation;
+        }
+        observation.alive = true;
+
+        let life = slot.entity().life();
+        let genome = &life.genome;
+        let ratio = |value: usize, max: usize| (value as f32 / max.max(1) as f32).min(1.0);
+        observation.stats = [
+            ratio(life.energy, genome.max_energy),
+            ratio(life.water, genome.max_water),
+            ratio(life.health, life.max_health()),
+            ratio(life.fatigue, genome.max_fatigue),
+        ];
+        observation.grid = self.world.build_grid(slot, self.radius);
+        observation
+    }
+}
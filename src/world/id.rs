@@ -0,0 +1,86 @@
+/// Canlı kimliklerini dağıtan sayaç
+///
+/// Kimlikler sadece artar, ölen ya da kaldırılan bir canlının kimliği
+/// başka bir canlıya asla verilmez. Böylece `Perception` veya `Intent`
+/// içinde kalmış eski bir `target_id` farklı bir canlıyı göstermez;
+/// World tarafında aranınca bulunamaz ve `None` döner.
+#[derive(Debug, Clone)]
+pub struct IdAllocator {
+    /// Bir sonraki verilecek kimlik
+    next: usize,
+}
+
+impl IdAllocator {
+    /// İlk kimliği `start` olan sayaç oluştur
+    pub fn new(start: usize) -> Self {
+        Self { next: start }
+    }
+
+    /// Verilen kimliklerden sonra gelen ilk kimlikten başlayan sayaç
+    /// Dışarıdan hazır kimlikle gelen canlılar için kullanılır.
+    pub fn after<I: IntoIterator<Item = usize>>(used: I) -> Self {
+        let mut ids = Self::new(1);
+        for id in used {
+            ids.reserve(id);
+        }
+        ids
+    }
+
+    /// Yeni ve daha önce verilmemiş bir kimlik üret
+    pub fn allocate(&mut self) -> usize {
+        let id = self.next;
+        self.next += 1;
+        id
+    }
+
+    /// Dışarıda verilmiş bir kimliği kullanılmış say
+    /// Sayaç hiçbir zaman geriye gitmez.
+    pub fn reserve(&mut self, id: usize) {
+        self.next = self.next.max(id + 1);
+    }
+
+    /// Bir sonraki verilecek kimlik (üretmeden bakar)
+    pub fn peek(&self) -> usize {
+        self.next
+    }
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn allocates_unique_ids() {
+        let mut ids = IdAllocator::default();
+        let given: HashSet<usize> = (0..100).map(|_| ids.allocate()).collect();
+        assert_eq!(given.len(), 100);
+        assert!(!given.contains(&0));
+        assert_eq!(ids.peek(), 101);
+    }
+
+    #[test]
+    fn reserve_only_moves_forward() {
+        let mut ids = IdAllocator::new(5);
+        ids.reserve(9);
+        assert_eq!(ids.peek(), 10);
+        ids.reserve(3);
+        assert_eq!(ids.peek(), 10);
+        assert_eq!(ids.allocate(), 10);
+        ids.reserve(10);
+        assert_eq!(ids.allocate(), 11);
+    }
+
+    #[test]
+    fn starts_after_used_ids() {
+        let mut ids = IdAllocator::after([4, 17, 2]);
+        assert_eq!(ids.allocate(), 18);
+        assert_eq!(IdAllocator::after([]).peek(), 1);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::entity::{
+    genome::Genome,
+    species::{Species, SpeciesRegistry},
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Soy ağacındaki tek bir canlının kaydı
+#[derive(Debug, Clone)]
+pub struct LineageRecord {
+    /// Canlının kimliği
+    pub id: usize,
+    /// Canlının türü
+    pub species: Species,
+    /// Annenin kimliği, kurucu canlılarda yoktur
+    pub mother_id: Option<usize>,
+    /// Babanın kimliği, kurucu canlılarda yoktur
+    pub father_id: Option<usize>,
+    /// Doğduğu (ya da dünyaya eklendiği) tick
+    pub tick: usize,
+    /// Doğumdaki genomu
+    pub genome: Genome,
+}
+
+impl LineageRecord {
+    /// Bilinen ebeveynler (önce anne)
+    pub fn parents(&self) -> impl Iterator<Item = usize> {
+        self.mother_id.into_iter().chain(self.father_id)
+    }
+
+    /// Ebeveyni olmayan, dünyaya dışarıdan eklenmiş canlı mı?
+    pub fn is_founder(&self) -> bool {
+        self.mother_id.is_none() && self.father_id.is_none()
+    }
+}
+
+/// Dünyaya katılan bütün canlıların soy kaydı
+///
+/// Canlı ölse de kaydı silinmez. Kimlikler sadece arttığı için
+/// ebeveynin kimliği her zaman yavrununkinden küçüktür.
+#[derive(Debug, Clone, Default)]
+pub struct Lineage {
+    /// Kimliğe göre sıralı kayıtlar
+    records: BTreeMap<usize, LineageRecord>,
+    /// Ebeveyn -> yavrular
+    children: HashMap<usize, Vec<usize>>,
+}
+
+impl Lineage {
+    /// Yeni kayıt ekle, aynı kimlik tekrar eklenirse eskisi korunur
+    pub fn record(&mut self, record: LineageRecord) {
+        if self.records.contains_key(&record.id) {
+            return;
+        }
+        for parent in record.parents() {
+            self.children.entry(parent).or_default().push(record.id);
+        }
+        self.records.insert(record.id, record);
+    }
+
+    /// Kimliği verilen canlının kaydı
+    pub fn get(&self, id: usize) -> Option<&LineageRecord> {
+        self.records.get(&id)
+    }
+
+    /// Kayıt sayısı
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Hiç kayıt yok mu?
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Bütün kayıtlar (kimlik sırasıyla)
+    pub fn records(&self) -> impl Iterator<Item = &LineageRecord> {
+        self.records.values()
+    }
+
+    /// Kurucu canlıların kimlikleri
+    pub fn founders(&self) -> Vec<usize> {
+        self.records()
+            .filter(|record| record.is_founder())
+            .map(|record| record.id)
+            .collect()
+    }
+
+    /// Canlının doğrudan yavruları
+    pub fn children(&self, id: usize) -> &[usize] {
+        self.children.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Bütün ataları (kimlik sırasıyla)
+    pub fn ancestors(&self, id: usize) -> Vec<usize> {
+        let mut found = BTreeSet::new();
+        let mut queue: Vec<usize> = self.get(id).into_iter().flat_map(|r| r.parents()).collect();
+        while let Some(next) = queue.pop() {
+            if found.insert(next)
+                && let Some(record) = self.get(next)
+            {
+                queue.extend(record.parents());
+            }
+        }
+        found.into_iter().collect()
+    }
+
+    /// Bütün torunları (kimlik sırasıyla)
+    pub fn descendants(&self, id: usize) -> Vec<usize> {
+        let mut found = BTreeSet::new();
+        let mut queue = self.children(id).to_vec();
+        while let Some(next) = queue.pop() {
+            if found.insert(next) {
+                queue.extend_from_slice(self.children(next));
+            }
+        }
+        found.into_iter().collect()
+    }
+
+    /// Akrabalık katsayısı (0..=1, akraba evliliğinde 1'i geçebilir)
+    /// Kardeşler ve ebeveyn-yavru 0.5, yarım kardeşler 0.25 çıkar.
+    pub fn relatedness(&self, a: usize, b: usize) -> f64 {
+        2.0 * self.kinship(a, b, &mut HashMap::new())
+    }
+
+    /// Soy katsayısı: iki canlıdan rastgele seçilen genlerin aynı atadan gelme ihtimali
+    /// Genç olan (kimliği büyük olan) ebeveynlerine açılarak hesaplanır.
+    fn kinship(&self, a: usize, b: usize, memo: &mut HashMap<(usize, usize), f64>) -> f64 {
+        let (old, young) = (a.min(b), a.max(b));
+        if let Some(value) = memo.get(&(old, young)) {
+            return *value;
+        }
+        let Some(record) = self.get(young) else {
+            return 0.0;
+        };
+        let value = if old == young {
+            // Kendisiyle: 1/2 (1 + akraba evliliği katsayısı)
+            let inbreeding = match (record.mother_id, record.father_id) {
+                (Some(mother), Some(father)) => self.kinship(mother, father, memo),
+                _ => 0.0,
+            };
+            0.5 * (1.0 + inbreeding)
+        } else {
+            let mut sum = 0.0;
+            for parent in record.parents() {
+                sum += self.kinship(parent, old, memo);
+            }
+            0.5 * sum
+        };
+        memo.insert((old, young), value);
+        value
+    }
+
+    /// Ana soy ağacı, Newick biçiminde
+    /// İki ebeveynli soy ağaç olmadığından yavrular annelerinin altına yazılır.
+    /// Dal uzunluğu, annenin ve yavrunun kayıt tickleri arasındaki farktır.
+    pub fn to_newick(&self, names: &SpeciesRegistry) -> String {
+        let roots: Vec<String> = self
+            .records()
+            .filter(|record| {
+                record
+                    .mother_id
+                    .is_none_or(|mother| self.get(mother).is_none())
+            })
+            .map(|record| self.newick_node(record, names))
+            .collect();
+        format!("({});", roots.join(","))
+    }
+
+    /// Tek düğüm ve annesi olduğu alt ağaç
+    fn newick_node(&self, record: &LineageRecord, names: &SpeciesRegistry) -> String {
+        let children: Vec<String> = self
+            .children(record.id)
+            .iter()
+            .filter_map(|child| self.get(*child))
+            .filter(|child| child.mother_id == Some(record.id))
+            .map(|child| {
+                let length = child.tick.saturating_sub(record.tick);
+                format!("{}:{}", self.newick_node(child, names), length)
+            })
+            .collect();
+        let label = format!("{}_{}", names.name(record.species), record.id);
+        if children.is_empty() {
+            label
+        } else {
+            format!("({}){}", children.join(","), label)
+        }
+    }
+
+    /// Soy grafiği, Graphviz DOT biçiminde
+    /// Anneden gelen kenarlar düz, babadan gelenler kesikli çizilir.
+    pub fn to_dot(&self, names: &SpeciesRegistry) -> String {
+        let mut out = String::from("digraph lineage {\n");
+        for record in self.records() {
+            let shape = if record.is_founder() {
+                "box"
+            } else {
+                "ellipse"
+            };
+            out.push_str(&format!(
+                "    n{} [label=\"{} #{}\\nt={}\" shape={}];\n",
+                record.id,
+                names.name(record.species),
+                record.id,
+                record.tick,
+                shape
+            ));
+        }
+        for record in self.records() {
+            if let Some(mother) = record.mother_id {
+                out.push_str(&format!("    n{} -> n{};\n", mother, record.id));
+            }
+            if let Some(father) = record.father_id {
+                out.push_str(&format!(
+                    "    n{} -> n{} [style=dashed];\n",
+                    father, record.id
+                ));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creatures::config::default_species;
+
+    fn record(id: usize, parents: Option<(usize, usize)>, tick: usize) -> LineageRecord {
+        LineageRecord {
+            id,
+            species: Species::HERBIVORE,
+            mother_id: parents.map(|(mother, _)| mother),
+            father_id: parents.map(|(_, father)| father),
+            tick,
+            genome: default_species("herbivore").info.genome.clone(),
+        }
+    }
+
+    /// 1, 2, 3 kurucu; 4 ve 5 kardeş (1 x 2), 6 yarım kardeş (1 x 3),
+    /// 7 kardeşlerin yavrusu (4 x 5)
+    fn family() -> Lineage {
+        let mut lineage = Lineage::default();
+        lineage.record(record(1, None, 0));
+        lineage.record(record(2, None, 0));
+        lineage.record(record(3, None, 0));
+        lineage.record(record(4, Some((1, 2)), 10));
+        lineage.record(record(5, Some((1, 2)), 10));
+        lineage.record(record(6, Some((1, 3)), 12));
+        lineage.record(record(7, Some((4, 5)), 30));
+        lineage
+    }
+
+    #[test]
+    fn relatedness_of_relatives() {
+        let lineage = family();
+        assert_eq!(lineage.relatedness(1, 1), 1.0);
+        assert_eq!(lineage.relatedness(4, 5), 0.5);
+        assert_eq!(lineage.relatedness(1, 4), 0.5);
+        assert_eq!(lineage.relatedness(4, 1), 0.5);
+        assert_eq!(lineage.relatedness(4, 6), 0.25);
+        assert_eq!(lineage.relatedness(1, 7), 0.5);
+        // Akraba evliliğinden doğan yavru ebeveynine daha yakındır
+        assert_eq!(lineage.relatedness(4, 7), 0.75);
+    }
+
+    #[test]
+    fn relatedness_of_strangers() {
+        let lineage = family();
+        assert_eq!(lineage.relatedness(1, 2), 0.0);
+        assert_eq!(lineage.relatedness(2, 6), 0.0);
+        assert_eq!(lineage.relatedness(99, 1), 0.0);
+        assert_eq!(lineage.relatedness(99, 99), 0.0);
+    }
+
+    #[test]
+    fn ancestors_and_descendants() {
+        let lineage = family();
+        assert_eq!(lineage.founders(), vec![1, 2, 3]);
+        assert_eq!(lineage.ancestors(7), vec![1, 2, 4, 5]);
+        assert_eq!(lineage.descendants(1), vec![4, 5, 6, 7]);
+        assert_eq!(lineage.children(3), &[6]);
+        assert!(lineage.ancestors(99).is_empty());
+        assert!(lineage.descendants(99).is_empty());
+    }
+
+    #[test]
+    fn keeps_first_record() {
+        let mut lineage = family();
+        lineage.record(record(4, None, 99));
+        assert_eq!(lineage.len(), 7);
+        assert_eq!(lineage.get(4).unwrap().tick, 10);
+        assert!(lineage.children(2).contains(&4));
+    }
+
+    #[test]
+    fn newick_follows_mothers() {
+        let names = SpeciesRegistry::default();
+        assert_eq!(
+            family().to_newick(&names),
+            "(((herbivore_7:20)herbivore_4:10,herbivore_5:10,herbivore_6:12)herbivore_1,\
+             herbivore_2,herbivore_3);"
+        );
+        assert_eq!(Lineage::default().to_newick(&names), "();");
+    }
+
+    #[test]
+    fn dot_draws_both_parents() {
+        let dot = family().to_dot(&SpeciesRegistry::default());
+        assert!(dot.starts_with("digraph lineage {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("n1 [label=\"herbivore #1\\nt=0\" shape=box];"));
+        assert!(dot.contains("n7 [label=\"herbivore #7\\nt=30\" shape=ellipse];"));
+        assert!(dot.contains("n4 -> n7;"));
+        assert!(dot.contains("n5 -> n7 [style=dashed];"));
+        assert!(!dot.contains("n2 -> n1"));
+    }
+}
@@ -0,0 +1,1652 @@
+pub mod combat;
+pub mod env;
+pub mod id;
+pub mod lineage;
+pub mod spawn;
+
+use crate::{
+    entity::{
+        Entity,
+        intent::Intent,
+        outcome::Outcome,
+        perception::*,
+        phase::EntityPhase,
+        reproduction::Sex,
+        signal::{MAX_SIGNAL_RANGE, Signal},
+        species::{Diet, Species, SpeciesRegistry},
+    },
+    gen_range,
+    logger::{LogLevel, Logger},
+    map::{
+        Map,
+        movement::{DIRECTION_ARRAY, Direction, Position},
+    },
+    world::{
+        combat::{KillRecord, ambush, strike},
+        id::IdAllocator,
+        lineage::{Lineage, LineageRecord},
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+/// Cesedin tamamen çürüyüp kaldırılmasına kadar geçen tick
+pub const CORPSE_DURATION: usize = 30;
+
+/// Tek bir yeme niyetinde leşten koparılan en fazla et
+pub const CORPSE_BITE: usize = 8;
+
+/// Ebeveynin yanındaki yavrusuna tick başına verdiği besin
+pub const NURSING_AMOUNT: usize = 3;
+
+/// Yeme planı: (canlı, hedef konum, adım maliyeti, leş kimliği)
+type EatPlan = (usize, Position, usize, Option<usize>);
+
+/// Canlının yönetim biçimi
+pub struct EntitySlot {
+    /// Canlının benzerhsiz kimlik numarası
+    pub id: usize,
+    /// Canlının konumu
+    pub pos: Position,
+    /// Canlının bulunduğu durum (aktif, uykuda, ölü, silinecek)
+    pub phase: EntityPhase,
+    /// Canlının verisi
+    pub base: Box<dyn Entity>,
+    /// Saldırı ile öldüyse, öldüren canlının kimliği
+    pub killed_by: Option<usize>,
+    /// Dünyada doğduysa annesinin kimliği
+    pub mother_id: Option<usize>,
+    /// Dünyada doğduysa babasının kimliği
+    pub father_id: Option<usize>,
+    /// Son hareketin yönü (sürü hizalanması için)
+    pub heading: Option<Direction>,
+    /// Bu tick saldırdığı canlı (sürü avında ortak hedef için)
+    pub target: Option<usize>,
+    /// Saldırı ile öldüyse, onu birlikte avlayan sürü (öldüren dahil)
+    pub hunters: Vec<usize>,
+}
+
+impl EntitySlot {
+    /// Yeni canlı oluştur
+    pub fn new(id: usize, pos: Position, phase: EntityPhase, base: Box<dyn Entity>) -> EntitySlot {
+        Self {
+            id,
+            pos,
+            phase,
+            base,
+            killed_by: None,
+            mother_id: None,
+            father_id: None,
+            heading: None,
+            target: None,
+            hunters: Vec::new(),
+        }
+    }
+
+    /// Canlıyı yeni konuma taşı ve hareket yönünü kaydet
+    pub fn move_to(&mut self, pos: Position) {
+        if let Some(dir) = self.pos.direction_to(pos) {
+            self.heading = Some(dir);
+        }
+        self.pos = pos;
+    }
+
+    /// Canlının şu anki hâliyle soy kaydı
+    pub fn lineage_record(&self, tick: usize) -> LineageRecord {
+        LineageRecord {
+            id: self.id,
+            species: self.base.species(),
+            mother_id: self.mother_id,
+            father_id: self.father_id,
+            tick,
+            genome: self.entity().life().genome.clone(),
+        }
+    }
+
+    /// Diğer canlı bu canlının ebeveyni ya da yavrusu mu?
+    pub fn relation_to(&self, other: &EntitySlot) -> Relation {
+        if self.mother_id == Some(other.id) || self.father_id == Some(other.id) {
+            Relation::Parent
+        } else if other.mother_id == Some(self.id) || other.father_id == Some(self.id) {
+            Relation::Offspring
+        } else {
+            Relation::Stranger
+        }
+    }
+
+    /// Canlının bulunduğu konum
+    pub fn position(&self) -> &Position {
+        &self.pos
+    }
+
+    /// Canlının bulunduğu konumu (değiştirilebilir)
+    pub fn position_mut(&mut self) -> &mut Position {
+        &mut self.pos
+    }
+
+    /// Canlıyı döndürür, okumak için
+    pub fn entity(&self) -> &dyn Entity {
+        self.base.as_ref()
+    }
+    /// Canlıyı döndürür, yazmak için
+    pub fn entity_mut(&mut self) -> &mut dyn Entity {
+        self.base.as_mut()
+    }
+
+    /// Canlı durumunu döndürür
+    pub fn phase(&self) -> &EntityPhase {
+        &self.phase
+    }
+
+    /// Canlının durumunu değiştirilebilir
+    pub fn phase_mut(&mut self) -> &mut EntityPhase {
+        &mut self.phase
+    }
+}
+
+/// Entity'ler burada tutulur,
+/// Intent verebilicek durumda ki Entity'lere
+/// Perception verip, Intent alarak
+/// Kendi içerisinde ki kurallar dahilinde
+/// Son kararı verir.
+pub struct World {
+    /// Simülasyon haritası
+    pub map: Map,
+
+    /// Tüm Canlıların ID, Pos ve Entity listesi
+    pub entities: Vec<EntitySlot>,
+
+    /// Simülasyon tur sayacı
+    pub tick_counter: usize,
+
+    /// Canlı kimlik dağıtıcısı, kimlikler tekrar kullanılmaz
+    pub ids: IdAllocator,
+
+    /// Kayıtlı türler, yeni türler çalışma anında eklenebilir
+    pub species: SpeciesRegistry,
+
+    /// Saldırı sonucu gerçekleşen ölümler (avlanma başarısı için)
+    pub kills: Vec<KillRecord>,
+
+    /// Bütün doğumların ve kurucu canlıların soy kaydı
+    pub lineage: Lineage,
+
+    /// Son tick gönderilen sinyaller, bir sonraki algıda duyulur
+    pub signals: Vec<Signal>,
+
+    /// Gelişmiş loglama
+    pub logger: Logger,
+}
+
+impl World {
+    pub fn new(x1: isize, x2: isize, y1: isize, y2: isize, entities: Vec<EntitySlot>) -> World {
+        // Haritayı oluştur
+        let mut map = Map::new(x1, x2, y1, y2);
+        // Haritanın % kısmına rastgele kaynak yerleştir.
+        // Kaynak yoğunluğunu biraz düşür (aşırı doygunluk davranışları baskılamasın)
+        map.populate_resources(0.05f32);
+        // Döndür
+        // Her çalıştırmada ayrı log dosyası oluştur (okunabilir tarih/saat)
+        let now = time::OffsetDateTime::now_utc();
+        let format =
+            time::format_description::parse("[year]-[month]-[day]_[hour]-[minute]-[second]")
+                .unwrap_or_else(|_| {
+                    time::format_description::parse("[year][month][day]_[hour][minute][second]")
+                        .unwrap()
+                });
+        let ts = now
+            .format(&format)
+            .unwrap_or_else(|_| "unknown_time".to_string());
+        let log_path = format!("logs/simulation_{}.log", ts);
+        let mut logger = Logger::new(&log_path);
+        logger.set_min_level(LogLevel::Info);
+        // Dışarıdan gelen kimlikler kullanılmış sayılır
+        let ids = IdAllocator::after(entities.iter().map(|slot| slot.id));
+        let mut lineage = Lineage::default();
+        for slot in &entities {
+            lineage.record(slot.lineage_record(0));
+        }
+        World {
+            map,
+            entities,
+            tick_counter: 0,
+            ids,
+            species: SpeciesRegistry::default(),
+            kills: Vec::new(),
+            lineage,
+            signals: Vec::new(),
+            logger,
+        }
+    }
+
+    /// Kurucu canlılar ve her birinin yaşayan torun sayısı
+    /// Uzun çalıştırmalarda hangi kurucunun soyunun sürdüğünü gösterir.
+    pub fn surviving_lines(&self) -> Vec<(usize, usize)> {
+        self.lineage
+            .founders()
+            .into_iter()
+            .map(|founder| {
+                let living = self
+                    .lineage
+                    .descendants(founder)
+                    .into_iter()
+                    .filter(|id| {
+                        self.slot(*id)
+                            .is_some_and(|slot| slot.phase.is_active() || slot.phase.is_sleeping())
+                    })
+                    .count();
+                (founder, living)
+            })
+            .collect()
+    }
+
+    /// Kimliği verilen canlıyı döndürür
+    /// Kimlikler tekrar kullanılmadığı için eski bir kimlik `None` döner.
+    pub fn slot(&self, id: usize) -> Option<&EntitySlot> {
+        self.entities.iter().find(|slot| slot.id == id)
+    }
+
+    /// Kimliği verilen canlıyı döndürür (değiştirilebilir)
+    pub fn slot_mut(&mut self, id: usize) -> Option<&mut EntitySlot> {
+        self.entities.iter_mut().find(|slot| slot.id == id)
+    }
+
+    /// Tick, bir zaman birimidir
+    /// Tick, canlının bulunduğu konumu baz alarak Perception oluşturur.
+    /// Entity, verilen Perception ile karar alır.
+    /// World, Perception -> Intent şeklinde yola koyulur.
+    /// Son adımda sonuca Intent Resolver karar verir.
+    /// BU KARAR KESİNLİK DEĞİLDİR, WORLD SON SÖZÜ SÖYLER
+    /// ÇAKIŞAN NİYETLER İÇİN WORLD İNSİYATİF ALABİLİR
+    pub fn tick(&mut self) {
+        self.tick_counter += 1;
+
+        // Removed aşamasındaki entityleri sil
+        self.entities
+            .retain(|slot| !matches!(slot.phase, EntityPhase::Removed));
+
+        let mut log_lines: Vec<String> = Vec::new();
+        log_lines.push(format!("=== Tick {} ===", self.tick_counter));
+
+        // Çakışma çözümü ve hızlı erişim için dolu hücre haritası
+        let mut occupied: HashMap<Position, usize> = self
+            .entities
+            .iter()
+            .filter(|slot| {
+                !matches!(
+                    slot.phase,
+                    EntityPhase::Corpse { .. } | EntityPhase::Removed
+                )
+            })
+            .map(|slot| (slot.pos, slot.id))
+            .collect();
+
+        // Tick sonunda sonuç bildirmek için yaşayanların başlangıç değerleri
+        let before: HashMap<usize, (usize, usize, usize)> = self
+            .entities
+            .iter()
+            .filter(|slot| slot.phase.is_active() || slot.phase.is_sleeping())
+            .map(|slot| {
+                let life = slot.entity().life();
+                (slot.id, (life.health, life.energy, life.water))
+            })
+            .collect();
+
+        let mut intents: Vec<(usize, Intent)> = Vec::new();
+
+        // Her entity için perception ve intent oluştur
+        for index in 0..self.entities.len() {
+            if !self.entities[index].phase.is_active() {
+                continue; // Sadece aktif canlılar karar verir
+            }
+            let perception = self.build_perception(&self.entities[index]);
+            // Canlı önce gördüklerini hafızasına işler, sonra karar verir
+            self.entities[index].entity_mut().observe(&perception);
+            let slot = &self.entities[index];
+            let intent = slot.entity().make_intent(perception);
+            intents.push((slot.id, intent));
+
+            // Niyet logu (Idle ise her 5 tick'te bir yaz)
+            let last_intent = intents.last().unwrap().1.clone();
+            let should_log_intent =
+                !matches!(last_intent, Intent::Idle { .. }) || self.tick_counter.is_multiple_of(5);
+            if should_log_intent {
+                log_lines.push(format!(
+                    "[Niyet] @{} {} Pos:{:?} => {:?}",
+                    slot.id,
+                    self.species.name(slot.base.species()),
+                    slot.pos,
+                    last_intent
+                ));
+            }
+        }
+
+        // Intentleri çöz
+        //
+        // 1. Move planları ve mate planlarını önceden topla
+        let mut move_plans: Vec<(usize, Position, usize)> = Vec::new();
+        let mut eat_plans: Vec<EatPlan> = Vec::new();
+        let mut drink_plans: Vec<(usize, Position, usize)> = Vec::new();
+        let mut mate_plans: Vec<(usize, usize)> = Vec::new();
+        let mut attack_plans: Vec<(usize, usize)> = Vec::new();
+        let mut flee_plans: Vec<(usize, Position, usize)> = Vec::new();
+        let mut sleep_plans: Vec<(usize, usize)> = Vec::new();
+        let mut rest_plans: Vec<usize> = Vec::new();
+        let mut signals: Vec<Signal> = Vec::new();
+
+        // Metabolizma maliyeti tick sonunda niyete göre hesaplanır
+        let chosen: HashMap<usize, Intent> = intents.iter().cloned().collect();
+
+        for (id, intent) in intents {
+            match intent {
+                Intent::Move { steps } => {
+                    if !steps.is_empty()
+                        && let Some(slot) = self.entities.iter_mut().find(|s| s.id == id)
+                    {
+                        let mut new_pos: Position = slot.pos;
+                        let mut cost: usize = 0;
+
+                        for dir in steps.0.iter() {
+                            if !self.map.is_walkable(new_pos + *dir)
+                                || !slot.base.life().can_move_for(cost + 1)
+                            {
+                                break;
+                            }
+                            cost += 1;
+                            new_pos = new_pos + *dir;
+                        }
+                        move_plans.push((id, new_pos, cost));
+                        log_lines.push(format!(
+                            "[Plan] Move  @{} {} -> {:?} adim:{}",
+                            slot.id,
+                            self.species.name(slot.base.species()),
+                            new_pos,
+                            cost
+                        ));
+                    }
+                }
+                Intent::Eat { at, corpse_id } => {
+                    if let Some(slot) = self.entities.iter().find(|s| s.id == id) {
+                        let mut new_pos: Position = slot.pos;
+                        let mut cost: usize = 0;
+                        for dir in at.0.iter() {
+                            if !self.map.is_walkable(new_pos + *dir)
+                                || !slot.base.life().can_move_for(cost + 1)
+                            {
+                                break;
+                            }
+                            cost += 1;
+                            new_pos = new_pos + *dir;
+                        }
+                        // Aynı hücredeyse de yeme planı üret
+                        eat_plans.push((id, new_pos, cost, corpse_id));
+                        log_lines.push(format!(
+                            "[Plan] Eat   @{} {} -> {:?} adim:{}",
+                            slot.id,
+                            self.species.name(slot.base.species()),
+                            new_pos,
+                            cost
+                        ));
+                    }
+                }
+                Intent::Drink { at } => {
+                    if let Some(slot) = self.entities.iter().find(|s| s.id == id) {
+                        let mut new_pos: Position = slot.pos;
+                        let mut cost: usize = 0;
+                        for dir in at.0.iter() {
+                            if !self.map.is_walkable(new_pos + *dir)
+                                || !slot.base.life().can_move_for(cost + 1)
+                            {
+                                break;
+                            }
+                            cost += 1;
+                            new_pos = new_pos + *dir;
+                        }
+                        // Aynı hücredeyse de içme planı üret
+                        drink_plans.push((id, new_pos, cost));
+                        log_lines.push(format!(
+                            "[Plan] Drink @{} {} -> {:?} adim:{}",
+                            slot.id,
+                            self.species.name(slot.base.species()),
+                            new_pos,
+                            cost
+                        ));
+                    }
+                }
+                Intent::Mate { target_id } => {
+                    mate_plans.push((id, target_id));
+                    log_lines.push(format!("[Plan] Mate  @{} -> @{}", id, target_id));
+                }
+                Intent::Attack { target_id } => {
+                    attack_plans.push((id, target_id));
+                    log_lines.push(format!("[Plan] Attack @{} -> @{}", id, target_id));
+                }
+                Intent::Flee { target_id } => {
+                    let target_pos = match self.entities.iter().find(|s| s.id == target_id) {
+                        Some(t) => t.pos,
+                        None => continue,
+                    };
+                    if let Some(slot) = self.entities.iter().find(|s| s.id == id) {
+                        let mut new_pos: Position = slot.pos;
+                        let mut cost: usize = 0;
+
+                        for _ in 0..slot.base.life().speed() {
+                            // Hedefe en çok uzaklaştıran yönü seç
+                            let mut best_dir: Option<Direction> = None;
+                            let mut best_dist: usize = new_pos.distance_to(target_pos);
+
+                            for dir in DIRECTION_ARRAY {
+                                let candidate = new_pos + dir;
+                                if !self.map.is_walkable(candidate) {
+                                    continue;
+                                }
+                                let dist = candidate.distance_to(target_pos);
+                                if dist > best_dist {
+                                    best_dist = dist;
+                                    best_dir = Some(dir);
+                                }
+                            }
+
+                            // Daha iyi bir yön yoksa, yürünebilir herhangi bir yönü seç
+                            let dir = match best_dir {
+                                Some(d) => d,
+                                None => {
+                                    let mut fallback: Option<Direction> = None;
+                                    for d in DIRECTION_ARRAY {
+                                        let candidate = new_pos + d;
+                                        if self.map.is_walkable(candidate) {
+                                            fallback = Some(d);
+                                            break;
+                                        }
+                                    }
+                                    let Some(d) = fallback else { break };
+                                    d
+                                }
+                            };
+                            if !slot.base.life().can_move_for(cost + 1) {
+                                break;
+                            }
+                            cost += 1;
+                            new_pos = new_pos + dir;
+                        }
+
+                        flee_plans.push((id, new_pos, cost));
+                        log_lines.push(format!(
+                            "[Plan] Flee  @{} -> {:?} (hedef @{}) adim:{}",
+                            id, new_pos, target_id, cost
+                        ));
+                    }
+                }
+                Intent::Idle { duration: _ } => {
+                    if let Some(slot) = self.entities.iter().find(|s| s.id == id) {
+                        // Hafif gezinme: %30 ihtimalle en az ziyaret edilen komşuya 1 adım
+                        const IDLE_MOVE_CHANCE: isize = 30;
+                        let roll = gen_range(1, 100);
+                        let mut wandered = false;
+                        if roll <= IDLE_MOVE_CHANCE && slot.base.life().can_move_for(1) {
+                            let memory = &slot.base.life().memory;
+                            let chosen = DIRECTION_ARRAY
+                                .iter()
+                                .map(|dir| slot.pos + *dir)
+                                .filter(|candidate| self.map.is_walkable(*candidate))
+                                .max_by_key(|candidate| {
+                                    memory.novelty(*candidate) * 10 + gen_range(0, 9) as usize
+                                });
+                            if let Some(pos) = chosen {
+                                wandered = true;
+                                move_plans.push((id, pos, 1));
+                                log_lines.push(format!(
+                                    "[Plan] Idle->Move @{} {} -> {:?} adim:1",
+                                    slot.id,
+                                    self.species.name(slot.base.species()),
+                                    pos
+                                ));
+                            }
+                        }
+                        // Yerinde bekleyen canlı dinlenir
+                        if !wandered {
+                            rest_plans.push(id);
+                        }
+                    }
+                }
+                Intent::Sleep { duration } => {
+                    sleep_plans.push((id, duration));
+                    log_lines.push(format!("[Plan] Sleep @{} sure:{}", id, duration));
+                }
+                Intent::Signal { kind, range } => {
+                    if let Some(slot) = self.entities.iter().find(|s| s.id == id) {
+                        let range = range.min(MAX_SIGNAL_RANGE);
+                        signals.push(Signal {
+                            kind,
+                            sender_id: id,
+                            species: slot.base.species(),
+                            sex: slot.base.life().sex,
+                            pos: slot.pos,
+                            range,
+                        });
+                        log_lines.push(format!(
+                            "[Plan] Signal @{} {} menzil:{}",
+                            id,
+                            kind.name(),
+                            range
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Sinyaller sonraki tick algısına iletilir
+        self.signals = signals;
+
+        // Saldırı hedefleri bir sonraki tick sürüye görünür
+        for slot in &mut self.entities {
+            slot.target = attack_plans
+                .iter()
+                .find(|(attacker, _)| *attacker == slot.id)
+                .map(|(_, target)| *target);
+        }
+
+        // ------------------------------
+        // 2. Move planlarını uygula (çakışma çözümü ile)
+        // ------------------------------
+        let mut move_candidates: HashMap<Position, Vec<(usize, Position, usize)>> = HashMap::new();
+        for plan in &move_plans {
+            move_candidates.entry(plan.1).or_default().push(*plan);
+        }
+
+        let mut move_winners: Vec<(usize, Position, usize)> = move_candidates
+            .into_values()
+            .map(|mut group| {
+                group.sort_by_key(|(id, _, _)| *id);
+                group[0]
+            })
+            .collect();
+
+        move_winners.sort_by_key(|(id, _, _)| *id);
+
+        for (id, new_pos, cost) in move_winners {
+            // Başka biri orayı tutuyorsa hareketi engelle
+            if let Some(other_id) = occupied.get(&new_pos)
+                && *other_id != id
+            {
+                log_lines.push(format!(
+                    "[Engel] Move  @{} -> {:?} (doluluk @{})",
+                    id, new_pos, other_id
+                ));
+                continue;
+            }
+
+            if let Some(slot) = self.entities.iter_mut().find(|s| s.id == id) {
+                // Eski pozisyonu boşalt
+                occupied.remove(&slot.pos);
+                slot.base.life_mut().on_move(cost);
+                slot.move_to(new_pos);
+                occupied.insert(new_pos, id);
+
+                log_lines.push(format!(
+                    "[Uygula] Move  @{} -> {:?} adim:{}",
+                    id, new_pos, cost
+                ));
+            }
+        }
+
+        // ------------------------------
+        // 3. Eat planlarını uygula (çakışma çözümü ile)
+        // ------------------------------
+        // Leşte öncelik onu avlayan sürünündür
+        let hunters: HashMap<usize, Vec<usize>> = self
+            .entities
+            .iter()
+            .filter(|slot| !slot.hunters.is_empty())
+            .map(|slot| (slot.id, slot.hunters.clone()))
+            .collect();
+        let is_hunter = |id: &usize, corpse_id: &Option<usize>| {
+            corpse_id
+                .and_then(|c| hunters.get(&c))
+                .is_some_and(|pack| pack.contains(id))
+        };
+
+        let mut eat_candidates: HashMap<Position, Vec<EatPlan>> = HashMap::new();
+        for plan in &eat_plans {
+            eat_candidates.entry(plan.1).or_default().push(*plan);
+        }
+
+        let mut eat_winners: Vec<EatPlan> = Vec::new();
+        // Leşin hücresine giremeyen avcılar yanından ısırır: (canlı, leş, leş konumu)
+        let mut shares: Vec<(usize, usize, Position)> = Vec::new();
+        for mut group in eat_candidates.into_values() {
+            group.sort_by_key(|(id, _, _, corpse_id)| (!is_hunter(id, corpse_id), *id));
+            for (id, pos, _, corpse_id) in &group[1..] {
+                if let Some(corpse_id) = corpse_id
+                    && is_hunter(id, &Some(*corpse_id))
+                {
+                    shares.push((*id, *corpse_id, *pos));
+                }
+            }
+            eat_winners.push(group[0]);
+        }
+
+        eat_winners.sort_by_key(|(id, _, _, _)| *id);
+        shares.sort_by_key(|(id, _, _)| *id);
+
+        for (id, new_pos, cost, corpse_id) in eat_winners {
+            if let Some(other_id) = occupied.get(&new_pos)
+                && *other_id != id
+            {
+                log_lines.push(format!(
+                    "[Engel] Eat   @{} -> {:?} (doluluk @{})",
+                    id, new_pos, other_id
+                ));
+                continue;
+            }
+
+            let Some(slot) = self.entities.iter_mut().find(|s| s.id == id) else {
+                continue;
+            };
+            occupied.remove(&slot.pos);
+            slot.move_to(new_pos);
+            slot.base.life_mut().on_move(cost);
+            occupied.insert(new_pos, id);
+
+            // Leş yeme: cesedin etinden ısırık al
+            if let Some(corpse_id) = corpse_id {
+                let bite = self
+                    .entities
+                    .iter_mut()
+                    .find(|s| s.id == corpse_id && s.pos == new_pos)
+                    .map(|corpse| corpse.phase.take_meat(CORPSE_BITE))
+                    .unwrap_or(0);
+                if bite > 0
+                    && let Some(slot) = self.entities.iter_mut().find(|s| s.id == id)
+                {
+                    slot.entity_mut().life_mut().eat(bite);
+                    log_lines.push(format!(
+                        "[Uygula] Eat   @{} -> leş @{} {:?} miktar:{}",
+                        id, corpse_id, new_pos, bite
+                    ));
+                }
+                continue;
+            }
+
+            if let Some(cell) = self.map.cell(new_pos)
+                && let crate::map::cell::Cell::Food { amount } = cell
+            {
+                //println!("[@{}] Entity eating from {:?}", slot.id, slot.pos);
+                let eat_amount = *amount.min(&5);
+                slot.entity_mut().life_mut().eat(eat_amount);
+                self.map.reduce_cell_amount(new_pos, eat_amount);
+
+                log_lines.push(format!(
+                    "[Uygula] Eat   @{} -> {:?} miktar:{}",
+                    id, new_pos, eat_amount
+                ));
+            }
+        }
+
+        // Sürü avı paylaşılır: leşin dibindeki avcılar yerinden ısırır
+        for (id, corpse_id, corpse_pos) in shares {
+            let adjacent = self.slot(id).is_some_and(|slot| {
+                (slot.pos.x - corpse_pos.x).abs() <= 1 && (slot.pos.y - corpse_pos.y).abs() <= 1
+            });
+            if !adjacent {
+                continue;
+            }
+            let bite = self
+                .entities
+                .iter_mut()
+                .find(|s| s.id == corpse_id && s.pos == corpse_pos)
+                .map(|corpse| corpse.phase.take_meat(CORPSE_BITE))
+                .unwrap_or(0);
+            if bite > 0
+                && let Some(slot) = self.slot_mut(id)
+            {
+                slot.entity_mut().life_mut().eat(bite);
+                log_lines.push(format!(
+                    "[Uygula] Paylas @{} -> leş @{} miktar:{}",
+                    id, corpse_id, bite
+                ));
+            }
+        }
+
+        // ------------------------------
+        // 3.1 Drink planlarını uygula (çakışma çözümü ile)
+        // ------------------------------
+        let mut drink_candidates: HashMap<Position, Vec<(usize, Position, usize)>> = HashMap::new();
+        for plan in &drink_plans {
+            drink_candidates.entry(plan.1).or_default().push(*plan);
+        }
+
+        let mut drink_winners: Vec<(usize, Position, usize)> = drink_candidates
+            .into_values()
+            .map(|mut group| {
+                group.sort_by_key(|(id, _, _)| *id);
+                group[0]
+            })
+            .collect();
+
+        drink_winners.sort_by_key(|(id, _, _)| *id);
+
+        for (id, new_pos, cost) in drink_winners {
+            if let Some(other_id) = occupied.get(&new_pos)
+                && *other_id != id
+            {
+                log_lines.push(format!(
+                    "[Engel] Drink @{} -> {:?} (doluluk @{})",
+                    id, new_pos, other_id
+                ));
+                continue;
+            }
+
+            if let Some(slot) = self.entities.iter_mut().find(|s| s.id == id) {
+                occupied.remove(&slot.pos);
+                slot.move_to(new_pos);
+                slot.base.life_mut().on_move(cost);
+                occupied.insert(new_pos, id);
+
+                if let Some(cell) = self.map.cell(new_pos)
+                    && let crate::map::cell::Cell::Water { amount } = cell
+                {
+                    let drink_amount = *amount.min(&5);
+                    slot.entity_mut().life_mut().restore_water(drink_amount);
+                    self.map.reduce_cell_amount(new_pos, drink_amount);
+
+                    log_lines.push(format!(
+                        "[Uygula] Drink @{} -> {:?} miktar:{}",
+                        id, new_pos, drink_amount
+                    ));
+                }
+            }
+        }
+
+        // ------------------------------
+        // 4. Mate planlarını uygula
+        // ------------------------------
+
+        let id_to_index: HashMap<usize, usize> = self
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.id, i))
+            .collect();
+
+        for (self_id, target_id) in mate_plans {
+            let self_index = id_to_index.get(&self_id).copied();
+            let target_index = id_to_index.get(&target_id).copied();
+
+            let (self_index, target_index) = match (self_index, target_index) {
+                (Some(a), Some(t)) if a != t => (a, t),
+                _ => continue,
+            };
+
+            // Aynı anda iki mutable borrow için split_at_mut kullanılır
+            let (left, right) = if self_index < target_index {
+                let (l, r) = self.entities.split_at_mut(target_index);
+                (l, r)
+            } else {
+                let (l, r) = self.entities.split_at_mut(self_index);
+                (r, l)
+            };
+
+            let (self_slot, target_slot) = if self_index < target_index {
+                (&mut left[self_index], &mut right[0])
+            } else {
+                (&mut left[0], &mut right[target_index])
+            };
+
+            // İkisi de aktif olmalı
+            if !self_slot.phase.is_active() || !target_slot.phase.is_active() {
+                continue;
+            }
+
+            // Yakınlık kontrolü (çapraz dahil komşu)
+            let dx = (self_slot.pos.x - target_slot.pos.x).abs();
+            let dy = (self_slot.pos.y - target_slot.pos.y).abs();
+            if dx > 1 || dy > 1 {
+                log_lines.push(format!(
+                    "[Engel] Mate  @{} + @{} (mesafe x:{} y:{})",
+                    self_id, target_id, dx, dy
+                ));
+                continue;
+            }
+
+            // Aynı türden olmalılar ve kur yapan üreyebilmeli
+            if self_slot.base.species() != target_slot.base.species()
+                || !self_slot.entity().life().can_reproduce()
+            {
+                log_lines.push(format!(
+                    "[Engel] Mate  @{} + @{} (uygun degil)",
+                    self_id, target_id
+                ));
+                continue;
+            }
+
+            // Kararı hedef verir
+            if !target_slot.entity().accept_mate(self_slot.entity().life()) {
+                // Reddedilen canlı bir süre aynı hedefe yönelmez
+                self_slot
+                    .entity_mut()
+                    .life_mut()
+                    .memory
+                    .remember_refusal(target_id);
+                log_lines.push(format!(
+                    "[Engel] Mate  @{} + @{} (reddedildi)",
+                    self_id, target_id
+                ));
+                continue;
+            }
+
+            // Üreme maliyetleri
+            self_slot.entity_mut().life_mut().on_reproduce();
+            target_slot.entity_mut().life_mut().on_reproduce();
+
+            // Dişi, erkeğin genomunu taşıyarak gebe kalır
+            let (mother, father) = if target_slot.entity().life().sex == Sex::Female {
+                (target_slot, self_slot)
+            } else {
+                (self_slot, target_slot)
+            };
+            let father_genome = father.entity().life().genome.clone();
+            mother
+                .entity_mut()
+                .life_mut()
+                .conceive(father.id, father_genome);
+
+            log_lines.push(format!(
+                "[Uygula] Mate  @{} + @{} => gebe @{}",
+                self_id, target_id, mother.id
+            ));
+        }
+
+        // ------------------------------
+        // 5. Attack planlarını uygula
+        // ------------------------------
+        let id_to_index: HashMap<usize, usize> = self
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.id, i))
+            .collect();
+
+        // Bu tick hedefe saldırmayı planlayan yaşayan canlılar (kimlik, tür, konum)
+        // Öldürücü vuruştan sonra sırası gelenler de sürüden sayılır.
+        let mut assailants: HashMap<usize, Vec<(usize, Species, Position)>> = HashMap::new();
+        for (attacker_id, target_id) in &attack_plans {
+            if let Some(attacker) = id_to_index.get(attacker_id).map(|i| &self.entities[*i])
+                && attacker.entity().life().is_alive()
+            {
+                assailants.entry(*target_id).or_default().push((
+                    *attacker_id,
+                    attacker.base.species(),
+                    attacker.pos,
+                ));
+            }
+        }
+        // Kuşatılan hedef tick başına tek bir saldırgana karşılık verebilir
+        let mut countered: HashSet<usize> = HashSet::new();
+        // Avına katıldığı ve ölen canlı sayısı
+        let mut kill_credit: HashMap<usize, usize> = HashMap::new();
+
+        for (attacker_id, target_id) in attack_plans {
+            let attacker_index = id_to_index.get(&attacker_id).copied();
+            let target_index = id_to_index.get(&target_id).copied();
+
+            let (attacker_index, target_index) = match (attacker_index, target_index) {
+                (Some(a), Some(t)) if a != t => (a, t),
+                _ => continue,
+            };
+
+            // Aynı anda iki mutable borrow için split_at_mut kullanılır
+            let (left, right) = if attacker_index < target_index {
+                let (l, r) = self.entities.split_at_mut(target_index);
+                (l, r)
+            } else {
+                let (l, r) = self.entities.split_at_mut(attacker_index);
+                (r, l)
+            };
+
+            let (attacker, target) = if attacker_index < target_index {
+                (&mut left[attacker_index], &mut right[0])
+            } else {
+                (&mut left[0], &mut right[target_index])
+            };
+
+            // Sadece aktif ya da uyuyan ve hâlâ yaşayan hedefe saldır
+            let target_asleep = target.phase.is_sleeping();
+            if !(target.phase.is_active() || target_asleep) || !target.entity().life().is_alive() {
+                log_lines.push(format!(
+                    "[Engel] Attack @{} -> @{} (hedef aktif degil)",
+                    attacker_id, target_id
+                ));
+                continue;
+            }
+            if !attacker.entity().life().is_alive() {
+                continue;
+            }
+
+            // Yakınlık kontrolü (çapraz dahil komşu)
+            let dx = (attacker.pos.x - target.pos.x).abs();
+            let dy = (attacker.pos.y - target.pos.y).abs();
+            if dx > 1 || dy > 1 {
+                log_lines.push(format!(
+                    "[Engel] Attack @{} -> @{} (mesafe x:{} y:{})",
+                    attacker_id, target_id, dx, dy
+                ));
+                continue;
+            }
+
+            // Saldırı vuruşu, uyuyan hedef savunmasızdır
+            let outcome = if target_asleep {
+                ambush(attacker.entity().life(), target.entity().life())
+            } else {
+                strike(attacker.entity().life(), target.entity().life())
+            };
+            attacker
+                .entity_mut()
+                .life_mut()
+                .consume_energy(outcome.cost);
+            target.entity_mut().life_mut().take_damage(outcome.damage);
+            log_lines.push(format!(
+                "[Uygula] Attack @{} -> @{} isabet:{} hasar:{}",
+                attacker_id, target_id, outcome.hit, outcome.damage
+            ));
+            let attacker_species = attacker.base.species();
+
+            if !target.entity().life().is_alive() {
+                // Bu tick hedefe saldıran, yanındaki aynı türden herkes avı paylaşır
+                let target_pos = target.pos;
+                let pack: Vec<usize> = assailants
+                    .get(&target_id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, species, pos)| {
+                        *species == attacker_species
+                            && (pos.x - target_pos.x).abs() <= 1
+                            && (pos.y - target_pos.y).abs() <= 1
+                    })
+                    .map(|(id, _, _)| *id)
+                    .collect();
+                for member in &pack {
+                    *kill_credit.entry(*member).or_default() += 1;
+                }
+                target.killed_by = Some(attacker_id);
+                target.hunters = pack.clone();
+                log_lines.push(format!(
+                    "[Uygula] Kill  @{} -> @{} suru:{:?}",
+                    attacker_id, target_id, pack
+                ));
+                self.kills.push(KillRecord {
+                    tick: self.tick_counter,
+                    killer_id: attacker_id,
+                    killer_species: attacker_species,
+                    victim_id: target_id,
+                    victim_species: target.base.species(),
+                    pack,
+                });
+                continue;
+            }
+
+            // Uyuyan hedef saldırı ile uyanır, bu tick karşılık veremez
+            if target_asleep {
+                target.phase.wake();
+                log_lines.push(format!("[Durum] Uyandi @{} (saldiri)", target_id));
+                continue;
+            }
+
+            // Hayatta kalan hedef karşılık verir
+            if !countered.insert(target_id) {
+                log_lines.push(format!(
+                    "[Durum] Kusatildi @{} (karsilik yok @{})",
+                    target_id, attacker_id
+                ));
+                continue;
+            }
+            let counter = strike(target.entity().life(), attacker.entity().life());
+            target.entity_mut().life_mut().consume_energy(counter.cost);
+            attacker.entity_mut().life_mut().take_damage(counter.damage);
+            log_lines.push(format!(
+                "[Uygula] Counter @{} -> @{} isabet:{} hasar:{}",
+                target_id, attacker_id, counter.hit, counter.damage
+            ));
+
+            if !attacker.entity().life().is_alive() {
+                attacker.killed_by = Some(target_id);
+                attacker.hunters = vec![target_id];
+                *kill_credit.entry(target_id).or_default() += 1;
+                self.kills.push(KillRecord {
+                    tick: self.tick_counter,
+                    killer_id: target_id,
+                    killer_species: target.base.species(),
+                    victim_id: attacker_id,
+                    victim_species: attacker.base.species(),
+                    pack: vec![target_id],
+                });
+                log_lines.push(format!("[Uygula] Kill  @{} -> @{}", target_id, attacker_id));
+            }
+        }
+
+        // ------------------------------
+        // 6. Flee planlarını uygula (çakışma çözümü ile)
+        // ------------------------------
+        let mut flee_candidates: HashMap<Position, Vec<(usize, Position, usize)>> = HashMap::new();
+        for plan in &flee_plans {
+            flee_candidates.entry(plan.1).or_default().push(*plan);
+        }
+
+        let mut flee_winners: Vec<(usize, Position, usize)> = flee_candidates
+            .into_values()
+            .map(|mut group| {
+                group.sort_by_key(|(id, _, _)| *id);
+                group[0]
+            })
+            .collect();
+
+        flee_winners.sort_by_key(|(id, _, _)| *id);
+
+        for (id, new_pos, cost) in flee_winners {
+            if let Some(other_id) = occupied.get(&new_pos)
+                && *other_id != id
+            {
+                log_lines.push(format!(
+                    "[Engel] Flee  @{} -> {:?} (doluluk @{})",
+                    id, new_pos, other_id
+                ));
+                continue;
+            }
+
+            if let Some(slot) = self.entities.iter_mut().find(|s| s.id == id) {
+                occupied.remove(&slot.pos);
+                if new_pos != slot.pos {
+                    slot.base.life_mut().on_move(cost);
+                    slot.move_to(new_pos);
+                } else {
+                    log_lines.push(format!(
+                        "[Engel] Flee  @{} -> {:?} (yerinde kaldı)",
+                        id, slot.pos
+                    ));
+                }
+                occupied.insert(slot.pos, id);
+
+                log_lines.push(format!(
+                    "[Uygula] Flee  @{} -> {:?} adim:{}",
+                    id, slot.pos, cost
+                ));
+            }
+        }
+
+        // ------------------------------
+        // 7. Sleep planlarını uygula
+        // ------------------------------
+        for (id, duration) in sleep_plans {
+            if let Some(slot) = self.entities.iter_mut().find(|s| s.id == id)
+                && slot.phase.is_active()
+            {
+                slot.phase = EntityPhase::Sleeping {
+                    remaining: duration,
+                };
+                log_lines.push(format!("[Uygula] Sleep @{} sure:{}", id, duration));
+            }
+        }
+
+        // ------------------------------
+        // 8. Bekleyenler dinlenir
+        // ------------------------------
+        for id in rest_plans {
+            if let Some(slot) = self.entities.iter_mut().find(|s| s.id == id)
+                && slot.phase.is_active()
+            {
+                slot.entity_mut().life_mut().rest();
+            }
+        }
+
+        // ------------------------------
+        // 8.1 Yavrular yanındaki ebeveynleri tarafından beslenir
+        // ------------------------------
+        let mut nursing: Vec<(usize, usize)> = Vec::new();
+        for (index, young) in self.entities.iter().enumerate() {
+            let living = young.phase.is_active() || young.phase.is_sleeping();
+            if !living || !young.entity().life().is_juvenile() {
+                continue;
+            }
+            let nurse = self.entities.iter().position(|adult| {
+                let life = adult.entity().life();
+                (adult.phase.is_active() || adult.phase.is_sleeping())
+                    && young.relation_to(adult) == Relation::Parent
+                    && !life.is_energy_low()
+                    && (young.pos.x - adult.pos.x).abs() <= 1
+                    && (young.pos.y - adult.pos.y).abs() <= 1
+            });
+            if let Some(nurse) = nurse {
+                nursing.push((index, nurse));
+            }
+        }
+        for (young, nurse) in nursing {
+            let nurse_life = self.entities[nurse].entity_mut().life_mut();
+            if nurse_life.is_energy_low() {
+                continue;
+            }
+            nurse_life.consume_energy(NURSING_AMOUNT);
+            self.entities[young]
+                .entity_mut()
+                .life_mut()
+                .eat(NURSING_AMOUNT);
+            log_lines.push(format!(
+                "[Uygula] Besleme @{} <- @{} miktar:{}",
+                self.entities[young].id, self.entities[nurse].id, NURSING_AMOUNT
+            ));
+        }
+
+        // ------------------------------
+        // 9. Yakına gelen tehdit uyuyanları uyandırır
+        // ------------------------------
+        let awake: Vec<(Position, Species)> = self
+            .entities
+            .iter()
+            .filter(|slot| slot.phase.is_active())
+            .map(|slot| (slot.pos, slot.base.species()))
+            .collect();
+        for slot in &mut self.entities {
+            if !slot.phase.is_sleeping() {
+                continue;
+            }
+            // Her tür kendi tehdit mesafesinde uyanır
+            let species = slot.base.species();
+            let range = slot.base.behaviour().threat_range;
+            let threatened = awake
+                .iter()
+                .any(|(pos, other)| *other != species && slot.pos.distance_to(*pos) <= range);
+            if threatened {
+                slot.phase.wake();
+                log_lines.push(format!("[Durum] Uyandi @{} (tehdit)", slot.id));
+            }
+        }
+
+        for slot in &mut self.entities {
+            // Fazın ve niyetin enerji bedeli, faz değişmeden önce hesaplanır
+            let cost = slot
+                .entity()
+                .life()
+                .metabolic_cost(&slot.phase, chosen.get(&slot.id));
+            slot.entity_mut().life_mut().consume_energy(cost);
+
+            // Sadece canlı olanların tick güncellemelerini uygula (yaş, enerji, speed reset vb.)
+            if slot.phase.is_active() {
+                slot.entity_mut().tick();
+            } else if slot.phase.is_sleeping() {
+                // Uyuyanlar yavaş metabolizma ile yaşamaya devam eder
+                slot.entity_mut().sleep_tick();
+                if slot.entity().life().fatigue == 0 {
+                    slot.phase.wake();
+                }
+            }
+            // Fazları güncelle ve ölüleri işaretle
+            slot.phase.tick();
+
+            let living = slot.phase.is_active() || slot.phase.is_sleeping();
+            if living && !slot.entity().life().is_alive() {
+                // Ceset, vücut kütlesi kadar et taşır ve yenebilir
+                let meat = slot.entity().life().body_mass();
+                slot.phase = EntityPhase::Corpse {
+                    remaining: CORPSE_DURATION,
+                    meat,
+                };
+
+                log_lines.push(format!(
+                    "[Durum] Ceset @{} et:{} olduren:{:?}",
+                    slot.id, meat, slot.killed_by
+                ));
+            }
+        }
+
+        // ------------------------------
+        // 10. Gebeliği dolan anneler doğurur
+        // ------------------------------
+        let mut newborns: Vec<EntitySlot> = Vec::new();
+        let mut offspring: HashMap<usize, usize> = HashMap::new();
+        for mother in &mut self.entities {
+            if !(mother.phase.is_active() || mother.phase.is_sleeping()) {
+                continue;
+            }
+            let Some(gestation) = mother.entity_mut().life_mut().take_due_gestation() else {
+                continue;
+            };
+
+            let litter = mother.entity().life().litter_size();
+            let mut born = 0;
+            for dir in DIRECTION_ARRAY {
+                if born >= litter {
+                    break;
+                }
+                // Yavru annenin yanında boş bir hücrede doğar
+                let child_pos = mother.pos + dir;
+                if !self.map.is_walkable(child_pos) || occupied.contains_key(&child_pos) {
+                    continue;
+                }
+                // Her yavru anneden enerji götürür
+                let cost = mother.entity().life().birth_cost();
+                if !mother.entity().life().enough_energy(cost) {
+                    break;
+                }
+                mother.entity_mut().life_mut().consume_energy(cost);
+
+                let child = mother.entity().reproduce(&gestation.father);
+                // Aynı tick içinde birden fazla doğum olsa da kimlikler çakışmaz
+                let new_id = self.ids.allocate();
+                occupied.insert(child_pos, new_id);
+                let mut newborn = EntitySlot::new(new_id, child_pos, EntityPhase::Active, child);
+                newborn.mother_id = Some(mother.id);
+                newborn.father_id = Some(gestation.father_id);
+                self.lineage
+                    .record(newborn.lineage_record(self.tick_counter));
+                newborns.push(newborn);
+                born += 1;
+
+                log_lines.push(format!(
+                    "[Uygula] Dogum @{} (baba @{}) => @{} {:?}",
+                    mother.id, gestation.father_id, new_id, child_pos
+                ));
+            }
+            if born == 0 {
+                log_lines.push(format!(
+                    "[Engel] Dogum @{} (bos komsu ya da enerji yok)",
+                    mother.id
+                ));
+            } else {
+                *offspring.entry(mother.id).or_default() += born;
+                *offspring.entry(gestation.father_id).or_default() += born;
+            }
+        }
+        self.entities.extend(newborns);
+
+        // ------------------------------
+        // 11. Sonuçlar canlılara bildirilir
+        // ------------------------------
+        for slot in &mut self.entities {
+            let Some(&(health, energy, water)) = before.get(&slot.id) else {
+                continue;
+            };
+            let life = slot.entity().life();
+            let outcome = Outcome {
+                tick: self.tick_counter,
+                health: life.health as isize - health as isize,
+                energy: life.energy as isize - energy as isize,
+                water: life.water as isize - water as isize,
+                offspring: offspring.get(&slot.id).copied().unwrap_or(0),
+                died: slot.phase.is_corpse(),
+                kills: kill_credit.get(&slot.id).copied().unwrap_or(0),
+            };
+            slot.entity_mut().outcome(&outcome);
+        }
+
+        // Tick sonunda logları yaz
+        self.logger.log_many(LogLevel::Info, &log_lines);
+    }
+
+    // Intentleri çöz ve uygulama fonksiyonu
+    //pub fn resolve_intent(&mut self, intents: Vec<(usize, Intent)>) {}
+
+    /// Entity "Intent" üretebilmesi için "Perception" üretir
+    pub fn build_perception(&self, current_slot: &EntitySlot) -> Perception {
+        let mut perception = Perception::empty();
+        perception.origin = current_slot.pos;
+        let radius = current_slot.base.life().vision_range(); // Görüş mesafesi (yarıçap)
+
+        // 1. Yakındaki Yiyecekleri Algıla
+        let found_foods = self.map.scan_foods_within(current_slot.pos, radius);
+        for (_f_pos, steps, amount) in found_foods {
+            perception.add_food(amount, false, steps);
+        }
+
+        // 1.1 Yakındaki Suları Algıla
+        let found_waters = self.map.scan_waters_within(current_slot.pos, radius);
+        for (_w_pos, steps, amount) in found_waters {
+            perception.add_water(amount, steps);
+        }
+
+        // 2. Yakındaki Diğer Canlıları ve Cesetleri Algıla
+        for other in &self.entities {
+            // Kendisini algılamasın
+            if other.id == current_slot.id {
+                continue;
+            }
+
+            // Mesafe kontrolü (Manhattan mesafesi kullanılıyor)
+            let dist = current_slot.pos.distance_to(other.pos);
+            if dist > radius {
+                continue;
+            }
+
+            match other.phase {
+                // Cesetler canlı değil, yenebilir leş olarak algılanır
+                EntityPhase::Corpse { meat, .. } => {
+                    if meat == 0 {
+                        continue;
+                    }
+                    if let Some(steps) = self.map.bfs_steps_to(current_slot.pos, other.pos, radius)
+                    {
+                        perception.add_corpse(other.id, meat, steps);
+                    }
+                }
+                EntityPhase::Removed => {}
+                _ => {
+                    // Canlıya giden yolu (Steps) BFS ile hesapla
+                    if let Some(steps) = self.map.bfs_steps_to(current_slot.pos, other.pos, radius)
+                    {
+                        // Algılanan canlıyı ekle (ID, Tür ve Adımlar)
+                        let other_life = other.entity().life();
+                        let species = other.entity().species();
+                        perception += PerceivedEntity {
+                            id: other.id,
+                            species,
+                            // Kayıtsız tür her şeyi yiyebilir sayılır
+                            diet: self
+                                .species
+                                .get(species)
+                                .map_or(Diet::Omnivore, |info| info.diet),
+                            power: other_life.combat_power(),
+                            sex: other_life.sex,
+                            relation: current_slot.relation_to(other),
+                            heading: other.heading,
+                            target: other.target,
+                            steps,
+                        };
+                    }
+                }
+            }
+        }
+
+        // 2.1 Menzili yeten sinyalleri duy (görüşten bağımsız)
+        for signal in &self.signals {
+            let distance = current_slot.pos.distance_to(signal.pos);
+            if distance <= signal.range {
+                perception.signals.push(PerceivedSignal {
+                    kind: signal.kind,
+                    sender_id: signal.sender_id,
+                    own: signal.sender_id == current_slot.id,
+                    species: signal.species,
+                    sex: signal.sex,
+                    pos: signal.pos,
+                    distance,
+                });
+            }
+        }
+
+        // 3. Yürünebilir Yönleri ve Mesafeleri Algıla
+        let walkable_map = self.map.walkable_distances(current_slot.pos);
+        for (dir, dist) in walkable_map {
+            perception.add_direction(dir, dist);
+        }
+
+        perception
+    }
+
+    /// Görüş açısını canlıyı merkez alan `radius` yarıçaplı ızgara olarak üretir
+    /// Aynı görüş kuralları kullanılır, liste ve ızgara aynı şeyi gösterir.
+    pub fn build_grid(&self, current_slot: &EntitySlot, radius: usize) -> PerceptionGrid {
+        let perception = self.build_perception(current_slot);
+        let mut grid = perception.to_grid(radius, self.species.len());
+
+        // Engeller: görüş içindeki yürünemeyen hücreler (harita dışı dahil)
+        let vision = current_slot.base.life().vision_range();
+        let radius = radius as isize;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let pos = Position::new(current_slot.pos.x + dx, current_slot.pos.y + dy);
+                if current_slot.pos.distance_to(pos) <= vision && !self.map.is_walkable(pos) {
+                    grid.add(OBSTACLE_CHANNEL, dx, dy, 1);
+                }
+            }
+        }
+        grid
+    }
+
+    // Bu pozisyonda entity var mı?
+    //pub fn has_entity(&self, pos: Position) -> bool { self.entity_pos.contains_key(&pos) }
+    // Bu pozisyondaki entity id'leri
+    //pub fn entities_at(&self, pos: Position) -> &[usize] { self.entity_pos.get(&pos).map(|v| v.as_slice()).unwrap_or(&[]) }
+    // Bu pozisyonda canlı entity var mı?
+    //pub fn has_alive_entity(&self, pos: Position) -> bool { self.entities_at(pos).iter().any(|id| self.entity_phase.get(id).is_some_and(|p| p.is_active()))}
+    // Bu pozisyonda ceset var mı?
+    //pub fn has_corpse(&self, pos: Position) -> bool {self.entities_at(pos).iter().any(|id| self.entity_phase.get(id).is_some_and(|p| p.is_corpse()))}
+    // Belirli bir merkez etrafında (Manhattan mesafe)
+    // entity olan pozisyonları döner
+    //pub fn nearby_entities(&self, center: Position, radius: usize) -> Vec<(Position, usize)> {      let mut result = Vec::new();        for (pos, ids) in self.entity_pos.iter() {            let dx = pos.x.abs_diff(center.x);            let dy = pos.y.abs_diff(center.y);            if dx + dy <= radius {                for id in ids {       result.push((*pos, *id));                }            }        }   result  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        creatures::{config::default_species, tree::TreeEntity},
+        entity::{genome::Genome, lifestate::LifeState, signal::SignalKind},
+        map::{cell::Cell, movement::Steps},
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Niyeti testten verilen ve aldığı sonuçları kaydeden canlı
+    pub(crate) struct Scripted {
+        pub(crate) life: LifeState,
+        pub(crate) species: Species,
+        /// Her tick aynen döndürülen niyet, test arada değiştirebilir
+        pub(crate) intent: Rc<RefCell<Intent>>,
+        /// World'un bildirdiği sonuçlar
+        pub(crate) outcomes: Rc<RefCell<Vec<Outcome>>>,
+    }
+
+    impl Scripted {
+        /// Türün gömülü genomu ile yetişkin, bekleyen canlı
+        pub(crate) fn new(name: &str, species: Species) -> Self {
+            Self {
+                life: LifeState::new(default_species(name).info.genome.clone()),
+                species,
+                intent: Rc::new(RefCell::new(Intent::Idle { duration: 1 })),
+                outcomes: Rc::new(RefCell::new(Vec::new())),
+            }
+        }
+    }
+
+    impl Entity for Scripted {
+        fn life(&self) -> &LifeState {
+            &self.life
+        }
+
+        fn life_mut(&mut self) -> &mut LifeState {
+            &mut self.life
+        }
+
+        fn species(&self) -> Species {
+            self.species
+        }
+
+        fn make_intent(&self, _view: Perception) -> Intent {
+            self.intent.borrow().clone()
+        }
+
+        fn outcome(&mut self, outcome: &Outcome) {
+            self.outcomes.borrow_mut().push(*outcome);
+        }
+
+        fn tick(&mut self) {
+            self.life.tick();
+        }
+
+        fn reproduce(&self, partner: &Genome) -> Box<dyn Entity> {
+            Box::new(Scripted {
+                life: LifeState::newborn(self.life.genome.inherit(partner)),
+                species: self.species,
+                intent: Rc::new(RefCell::new(Intent::Idle { duration: 1 })),
+                outcomes: Rc::new(RefCell::new(Vec::new())),
+            })
+        }
+    }
+
+    /// Testin canlıya niyet verip sonuçlarını okuyabildiği kulplar
+    pub(crate) type Handles = (Rc<RefCell<Intent>>, Rc<RefCell<Vec<Outcome>>>);
+
+    /// Türü isimle verilen `Scripted` canlıyı konuma ekle
+    pub(crate) fn spawn_scripted(world: &mut World, name: &str, pos: Position) -> (usize, Handles) {
+        let species = world.species.by_name(name).unwrap();
+        let entity = Scripted::new(name, species);
+        let handles = (entity.intent.clone(), entity.outcomes.clone());
+        let id = world.spawn_entity(Box::new(entity), pos).unwrap();
+        (id, handles)
+    }
+
+    /// Kaynaksız, `size` x `size` ve her hücresi yürünebilir dünya
+    pub(crate) fn open_world(size: isize) -> World {
+        let mut world = World::new(0, size - 1, 0, size - 1, Vec::new());
+        for x in 0..size {
+            for y in 0..size {
+                let pos = Position::new(x, y);
+                // Hiç kaynak düşmemiş chunk haritada yoktur, önce oluşturulur
+                world.map.set_cell(pos, Cell::Water { amount: 1 });
+                world.map.clear_cell(pos);
+            }
+        }
+        world
+    }
+
+    #[test]
+    fn removed_ids_are_not_reused() {
+        let mut world = open_world(8);
+        let first: Vec<usize> = (0..3)
+            .map(|i| world.spawn("herbivore", Position::new(i, 0)).unwrap())
+            .collect();
+        assert_eq!(first, vec![1, 2, 3]);
+
+        let last = *first.last().unwrap();
+        *world.slot_mut(last).unwrap().phase_mut() = EntityPhase::Removed;
+        world.tick();
+        assert!(world.slot(last).is_none());
+
+        let next = world.spawn("herbivore", Position::new(7, 7)).unwrap();
+        assert!(next > last);
+        assert_eq!(world.entities.iter().filter(|s| s.id == next).count(), 1);
+    }
+
+    #[test]
+    fn pack_kill_and_carcass_go_to_adjacent_same_species() {
+        let mut world = open_world(10);
+        let prey_pos = Position::new(5, 5);
+
+        // Uyuyan av pusuda her vuruşta yara alır, tek can ile ilk vuruşta ölür
+        let mut prey = TreeEntity::preset("herbivore");
+        prey.life_state.health = 1;
+        let prey_id = world.spawn_entity(Box::new(prey), prey_pos).unwrap();
+        *world.slot_mut(prey_id).unwrap().phase_mut() = EntityPhase::Sleeping { remaining: 10 };
+
+        // İki etçil avın dibinde, biri uzakta; hepçil de dibinde ama başka tür
+        let scene = [
+            ("carnivore", Position::new(4, 5)),
+            ("carnivore", Position::new(6, 5)),
+            ("carnivore", Position::new(5, 7)),
+            ("omnivore", Position::new(5, 4)),
+        ];
+        let hunters: Vec<(usize, Handles)> = scene
+            .iter()
+            .map(|(name, pos)| spawn_scripted(&mut world, name, *pos))
+            .collect();
+        let ids: Vec<usize> = hunters.iter().map(|(id, _)| *id).collect();
+        for (_, (intent, _)) in &hunters {
+            *intent.borrow_mut() = Intent::Attack { target_id: prey_id };
+        }
+
+        world.tick();
+
+        let record = world.kills.last().expect("av ölmeli");
+        assert_eq!(record.victim_id, prey_id);
+        assert_eq!(record.killer_id, ids[0]);
+        assert_eq!(record.pack, vec![ids[0], ids[1]]);
+        let corpse = world.slot(prey_id).unwrap();
+        assert!(corpse.phase.is_corpse());
+        assert_eq!(corpse.hunters, vec![ids[0], ids[1]]);
+        let kills: Vec<usize> = hunters
+            .iter()
+            .map(|(_, (_, outcomes))| outcomes.borrow().last().unwrap().kills)
+            .collect();
+        assert_eq!(kills, vec![1, 1, 0, 0]);
+
+        // Hepsi leşi yemeye gelir; sadece avı yapan sürü et alır
+        for (id, (intent, _)) in &hunters {
+            // Tok canlının enerjisi artmaz, yediği görülsün diye acıktır
+            let slot = world.slot_mut(*id).unwrap();
+            slot.entity_mut().life_mut().energy /= 2;
+            let pos = slot.pos;
+            let mut steps = Vec::new();
+            let mut at = pos;
+            while let Some(dir) = at.direction_to(prey_pos) {
+                steps.push(dir);
+                at = at + dir;
+            }
+            *intent.borrow_mut() = Intent::Eat {
+                at: Steps::new(steps),
+                corpse_id: Some(prey_id),
+            };
+        }
+        let EntityPhase::Corpse { meat: before, .. } = world.slot(prey_id).unwrap().phase else {
+            unreachable!()
+        };
+
+        world.tick();
+
+        let EntityPhase::Corpse { meat: after, .. } = world.slot(prey_id).unwrap().phase else {
+            panic!("leş bitmemeli")
+        };
+        assert_eq!(before - after, 2 * CORPSE_BITE);
+        let energy: Vec<isize> = hunters
+            .iter()
+            .map(|(_, (_, outcomes))| outcomes.borrow().last().unwrap().energy)
+            .collect();
+        assert!(
+            energy[0] > 0 && energy[1] > 0,
+            "sürü et almalı: {:?}",
+            energy
+        );
+        assert!(
+            energy[2] <= 0 && energy[3] <= 0,
+            "diğerleri et almamalı: {:?}",
+            energy
+        );
+    }
+
+    #[test]
+    fn signal_is_heard_next_tick_within_range_only() {
+        let mut world = open_world(20);
+        let (sender, (intent, _)) = spawn_scripted(&mut world, "herbivore", Position::new(0, 0));
+        let (near, _) = spawn_scripted(&mut world, "herbivore", Position::new(6, 0));
+        let (far, _) = spawn_scripted(&mut world, "herbivore", Position::new(9, 0));
+        // Dinleyenler göndereni göremez, sadece duyabilir
+        for id in [near, far] {
+            world
+                .slot_mut(id)
+                .unwrap()
+                .entity_mut()
+                .life_mut()
+                .genome
+                .vision_range = 2;
+        }
+        let heard = |world: &World, id: usize| {
+            let perception = world.build_perception(world.slot(id).unwrap());
+            assert!(perception.entities.iter().all(|e| e.id != sender));
+            perception
+                .signals
+                .iter()
+                .map(|s| (s.kind, s.sender_id, s.distance))
+                .collect::<Vec<_>>()
+        };
+
+        // N: çağrı gönderilir
+        *intent.borrow_mut() = Intent::Signal {
+            kind: SignalKind::Alarm,
+            range: 8,
+        };
+        world.tick();
+        // N+1: menzildeki duyar, menzil dışındaki duymaz
+        assert_eq!(heard(&world, near), vec![(SignalKind::Alarm, sender, 6)]);
+        assert!(heard(&world, far).is_empty());
+
+        *intent.borrow_mut() = Intent::Idle { duration: 1 };
+        world.tick();
+        // N+2: çağrı tekrarlanmadıkça bir kez duyulur
+        assert!(heard(&world, near).is_empty());
+        assert!(world.signals.is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    entity::{Entity, phase::EntityPhase, species::EntityFactory},
+    gen_range,
+    map::movement::Position,
+    world::{EntitySlot, World},
+};
+use std::fmt;
+
+/// Bölgeye rastgele yerleştirmede, canlı başına en fazla deneme
+const SPAWN_ATTEMPTS: usize = 20;
+
+/// Canlı dünyaya eklenemediğinde dönen hata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpawnError {
+    /// Bu isimde kayıtlı bir tür yok
+    UnknownSpecies(String),
+    /// Tür kayıtlı ama canlı üretecek fabrikası yok
+    NoFactory(String),
+    /// Hücre harita dışında ya da üzerinde yürünemiyor
+    NotWalkable(Position),
+    /// Hücrede başka bir canlı var (kimliği ile)
+    Occupied(Position, usize),
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnError::UnknownSpecies(name) => write!(f, "'{}' türü kayıtlı değil", name),
+            SpawnError::NoFactory(name) => write!(f, "'{}' türünün fabrikası yok", name),
+            SpawnError::NotWalkable(pos) => write!(f, "{:?} üzerinde yürünemiyor", pos),
+            SpawnError::Occupied(pos, id) => write!(f, "{:?} dolu (@{})", pos, id),
+        }
+    }
+}
+
+impl std::error::Error for SpawnError {}
+
+impl World {
+    /// Hücre yeni bir canlı için uygun mu?
+    /// Yürünebilir olmalı ve üzerinde kaldırılmamış bir canlı ya da ceset olmamalı.
+    pub fn check_free(&self, pos: Position) -> Result<(), SpawnError> {
+        if !self.map.is_walkable(pos) {
+            return Err(SpawnError::NotWalkable(pos));
+        }
+        if let Some(slot) = self
+            .entities
+            .iter()
+            .find(|e| e.pos == pos && !matches!(e.phase, EntityPhase::Removed))
+        {
+            return Err(SpawnError::Occupied(pos, slot.id));
+        }
+        Ok(())
+    }
+
+    /// Hücre boş ve yürünebilir mi?
+    pub fn is_free(&self, pos: Position) -> bool {
+        self.check_free(pos).is_ok()
+    }
+
+    /// Hazır bir canlıyı dünyaya ekle, yeni kimliğini döndür
+    /// Kayıtlı bir fabrikası olmayan canlılar için kullanılır.
+    pub fn spawn_entity(
+        &mut self,
+        entity: Box<dyn Entity>,
+        pos: Position,
+    ) -> Result<usize, SpawnError> {
+        self.check_free(pos)?;
+        let id = self.ids.allocate();
+        let slot = EntitySlot::new(id, pos, EntityPhase::Active, entity);
+        self.lineage.record(slot.lineage_record(self.tick_counter));
+        self.entities.push(slot);
+        Ok(id)
+    }
+
+    /// İsmi verilen türden yeni bir canlıyı konuma ekle
+    /// Canlı türün kayıtlı fabrikası ile üretilir, kimliği World verir.
+    pub fn spawn(&mut self, species_name: &str, pos: Position) -> Result<usize, SpawnError> {
+        let factory = self.factory_for(species_name)?;
+        self.check_free(pos)?;
+        self.spawn_entity(factory(), pos)
+    }
+
+    /// İsmi verilen türden `count` kadar canlıyı bölgede rastgele boş hücrelere ekle
+    /// Bölge iki köşe ile verilir (dahil). Yeterli boş hücre bulunamazsa
+    /// eklenebilenlerin kimlikleri döner; sadece tür hataları `Err` olur.
+    pub fn spawn_many(
+        &mut self,
+        species_name: &str,
+        count: usize,
+        corner_a: Position,
+        corner_b: Position,
+    ) -> Result<Vec<usize>, SpawnError> {
+        let factory = self.factory_for(species_name)?;
+        let (min_x, max_x) = (corner_a.x.min(corner_b.x), corner_a.x.max(corner_b.x));
+        let (min_y, max_y) = (corner_a.y.min(corner_b.y), corner_a.y.max(corner_b.y));
+
+        let mut ids = Vec::with_capacity(count);
+        for _ in 0..count * SPAWN_ATTEMPTS {
+            if ids.len() == count {
+                break;
+            }
+            let pos = Position::new(gen_range(min_x, max_x), gen_range(min_y, max_y));
+            if self.is_free(pos)
+                && let Ok(id) = self.spawn_entity(factory(), pos)
+            {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Tür adından fabrikayı bul
+    fn factory_for(&self, species_name: &str) -> Result<EntityFactory, SpawnError> {
+        let species = self
+            .species
+            .by_name(species_name)
+            .ok_or_else(|| SpawnError::UnknownSpecies(species_name.to_string()))?;
+        self.species
+            .factory(species)
+            .cloned()
+            .ok_or_else(|| SpawnError::NoFactory(species_name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{creatures::tree::TreeEntity, entity::species::Species, world::tests::open_world};
+
+    #[test]
+    fn rejects_unknown_species() {
+        let mut world = open_world(4);
+        assert_eq!(
+            world.spawn("dragon", Position::new(1, 1)),
+            Err(SpawnError::UnknownSpecies("dragon".to_string()))
+        );
+        assert_eq!(
+            world.spawn_many("dragon", 2, Position::new(0, 0), Position::new(3, 3)),
+            Err(SpawnError::UnknownSpecies("dragon".to_string()))
+        );
+        assert!(world.entities.is_empty());
+    }
+
+    #[test]
+    fn rejects_species_without_factory() {
+        let mut world = open_world(4);
+        let mut info = world.species.get(Species::HERBIVORE).unwrap().clone();
+        info.name = "statue".to_string();
+        world.species.register(info);
+        assert_eq!(
+            world.spawn("statue", Position::new(1, 1)),
+            Err(SpawnError::NoFactory("statue".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_occupied_cell() {
+        let mut world = open_world(4);
+        let pos = Position::new(2, 1);
+        let id = world.spawn("herbivore", pos).unwrap();
+        assert_eq!(
+            world.spawn("carnivore", pos),
+            Err(SpawnError::Occupied(pos, id))
+        );
+        assert_eq!(
+            world.spawn_entity(Box::new(TreeEntity::preset("herbivore")), pos),
+            Err(SpawnError::Occupied(pos, id))
+        );
+        assert_eq!(world.entities.len(), 1);
+    }
+
+    #[test]
+    fn rejects_cell_outside_map() {
+        let mut world = open_world(4);
+        for pos in [Position::new(4, 0), Position::new(-1, 2)] {
+            assert_eq!(
+                world.spawn("herbivore", pos),
+                Err(SpawnError::NotWalkable(pos))
+            );
+        }
+        assert!(world.entities.is_empty());
+    }
+
+    #[test]
+    fn spawn_many_stops_when_area_is_full() {
+        let mut world = open_world(6);
+        let ids = world
+            .spawn_many("herbivore", 6, Position::new(1, 1), Position::new(2, 2))
+            .unwrap();
+        assert_eq!(ids.len(), 4);
+        assert!(
+            world
+                .entities
+                .iter()
+                .all(|slot| { (1..=2).contains(&slot.pos.x) && (1..=2).contains(&slot.pos.y) })
+        );
+
+        let more = world
+            .spawn_many("herbivore", 3, Position::new(2, 2), Position::new(1, 1))
+            .unwrap();
+        assert!(more.is_empty());
+    }
+}